@@ -0,0 +1,97 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            error.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Errors encountered while parsing statements.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::error;
+use std::fmt;
+use std::io;
+
+///////////////////////////////////////////////////////////////////////////////
+// Error
+////
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+
+    Csv(csv::Error),
+
+    // The statement couldn't be converted to text, e.g. by pdftotext.
+    Conversion(String),
+
+    // A line of the statement couldn't be understood. Lines are counted from
+    // one.
+    Parse { line: usize, message: String },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn parse<S: ToString>(line: usize, message: S) -> Self {
+        Self::Parse {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Csv(e) => write!(f, "{}", e),
+            Self::Conversion(message) => {
+                write!(f, "conversion failed: {}", message)
+            }
+            Self::Parse { line, message } => {
+                write!(f, "line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(value: csv::Error) -> Self {
+        Self::Csv(value)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,311 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            fecccu.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Parser for the statements of FECCCU, as converted to text
+//                  by pdftotext.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::path::Path;
+use std::process::Command;
+
+use chrono::NaiveDate;
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::statement::*;
+
+// Transfers between the accounts of the statement, which are imported as one
+// account, so these would only add noise.
+const INTERNAL_TRANSFERS: &[&str] = &["OVERDRAFT TRANSFER", "PC CU TRANSFER"];
+
+// A beginning or ending balance row, which may not have a date.
+struct BalanceRow {
+    number: usize,
+    date: Option<NaiveDate>,
+    amount: i64,
+}
+
+// The balance rows of every table, kept until the period of the statement is
+// known.
+#[derive(Default)]
+struct BalanceRows {
+    beginning: Vec<BalanceRow>,
+    ending: Vec<BalanceRow>,
+}
+
+// Convert a PDF statement to text, preserving its layout. This requires
+// pdftotext, from poppler.
+pub fn pdf_to_text<P: AsRef<Path>>(path: P) -> Result<String> {
+    let output = Command::new("pdftotext")
+        .arg("-layout")
+        .arg(path.as_ref())
+        .arg("-")
+        .output()?;
+    if !output.status.success() {
+        return Err(Error::Conversion(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|e| Error::Conversion(e.to_string()))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FecccuParser
+// A statement has a table of transactions for each account, each introduced
+// by "Transaction Detail". A table may span several pages, repeating its
+// header row on each. Descriptions may continue onto the following lines.
+// Each row has a date, a description, an amount and the running balance, and
+// the table opens and closes with rows for the beginning and ending balance.
+// Balance rows without a date are at the start or the close of the statement
+// period.
+////
+
+pub struct FecccuParser {
+    table: Regex,
+    header: Regex,
+    check_recon: Regex,
+    beginning_balance: Regex,
+    ending_balance: Regex,
+    page_break: Regex,
+    transaction: Regex,
+    columns: Regex,
+    prefix: Regex,
+}
+
+impl Default for FecccuParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FecccuParser {
+    pub fn new() -> Self {
+        let regex = |pattern| Regex::new(pattern).unwrap();
+        FecccuParser {
+            table: regex(r"^\s*Transaction Detail"),
+            header: regex(r"^\s*Date\s*Transaction Type"),
+            check_recon: regex(r"\*\* Check Recon \*\*\s*$"),
+            beginning_balance: regex(
+                r"^\s*([0-9/]+)?\s*\* Beginning Balance \*(.*)$",
+            ),
+            ending_balance: regex(
+                r"^\s*([0-9/]+)?\s*\*\* Ending Balance \*\*(.*)$",
+            ),
+            page_break: regex(r"Page [0-9]+ of [0-9]+\s*$"),
+            transaction: regex(r"^\s*([0-9/]+)\s+(.*)$"),
+            columns: regex(r"\s{2,}"),
+            prefix: regex(r"ACH/|(POS|DBT)/WDR[ #*][0-9]* "),
+        }
+    }
+
+    // Consume lines up to and including the next header row. On statements
+    // with a check reconciliation form, the header row spans two lines.
+    fn skip_header<'a, I>(&self, lines: &mut I)
+    where
+        I: Iterator<Item = (usize, &'a str)>,
+    {
+        for (_, line) in lines.by_ref() {
+            if self.header.is_match(line) {
+                if self.check_recon.is_match(line) {
+                    lines.next();
+                }
+                return;
+            }
+        }
+    }
+
+    // A beginning or ending balance row. The date is optional.
+    fn balance(
+        &self,
+        pattern: &Regex,
+        number: usize,
+        line: &str,
+    ) -> Result<BalanceRow> {
+        let captures = pattern.captures(line).unwrap();
+        let date = match captures.get(1) {
+            Some(date) => {
+                Some(parse_date(date.as_str()).ok_or_else(|| {
+                    Error::parse(
+                        number,
+                        format!("invalid date {:?}", date.as_str()),
+                    )
+                })?)
+            }
+            None => None,
+        };
+        let amount = captures
+            .get(2)
+            .and_then(|rest| self.columns.split(rest.as_str().trim()).last())
+            .and_then(parse_amount)
+            .ok_or_else(|| {
+                Error::parse(number, "balance row has no amount")
+            })?;
+        Ok(BalanceRow {
+            number,
+            date,
+            amount,
+        })
+    }
+
+    fn transaction(
+        &self,
+        number: usize,
+        line: &str,
+    ) -> Result<StatementTransaction> {
+        let captures = self.transaction.captures(line).unwrap();
+        let date = &captures[1];
+        let date = parse_date(date).ok_or_else(|| {
+            Error::parse(number, format!("invalid date {:?}", date))
+        })?;
+
+        // The description, the amount and the running balance.
+        let columns =
+            self.columns.split(captures[2].trim()).collect::<Vec<_>>();
+        if columns.len() < 3 {
+            return Err(Error::parse(
+                number,
+                "expected a description, an amount and a balance",
+            ));
+        }
+        let amount = columns[columns.len() - 2];
+        let amount = parse_amount(amount).ok_or_else(|| {
+            Error::parse(number, format!("invalid amount {:?}", amount))
+        })?;
+        Ok(StatementTransaction {
+            date,
+            description: columns[0].to_string(),
+            amount,
+        })
+    }
+
+    // Parse one table, through its ending balance.
+    fn parse_table<'a, I>(
+        &self,
+        lines: &mut I,
+        statement: &mut Statement,
+        balances: &mut BalanceRows,
+    ) -> Result<()>
+    where
+        I: Iterator<Item = (usize, &'a str)>,
+    {
+        self.skip_header(lines);
+        let mut transactions: Vec<StatementTransaction> = Vec::new();
+        while let Some((number, line)) = lines.next() {
+            if self.ending_balance.is_match(line) {
+                balances.ending.push(self.balance(
+                    &self.ending_balance,
+                    number,
+                    line,
+                )?);
+                break;
+            } else if self.beginning_balance.is_match(line) {
+                balances.beginning.push(self.balance(
+                    &self.beginning_balance,
+                    number,
+                    line,
+                )?);
+            } else if self.page_break.is_match(line) {
+                self.skip_header(lines);
+            } else if line.trim().is_empty() {
+                continue;
+            } else if self.transaction.is_match(line) {
+                transactions.push(self.transaction(number, line)?);
+            } else {
+                let transaction =
+                    transactions.last_mut().ok_or_else(|| {
+                        Error::parse(
+                            number,
+                            "description continues, but there's no \
+                             transaction to continue",
+                        )
+                    })?;
+                transaction.description.push(' ');
+                transaction.description.push_str(line.trim());
+            }
+        }
+        statement.transactions.extend(transactions);
+        Ok(())
+    }
+}
+
+impl StatementParser for FecccuParser {
+    fn parse(&self, text: &str) -> Result<Statement> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line));
+        let mut statement = Statement::default();
+        let mut balances = BalanceRows::default();
+        while let Some((_, line)) = lines.next() {
+            if self.table.is_match(line) {
+                self.parse_table(&mut lines, &mut statement, &mut balances)?;
+            }
+        }
+
+        // Undated balances are at the start or the close of the period.
+        let dates = statement
+            .transactions
+            .iter()
+            .map(|transaction| transaction.date)
+            .chain(balances.beginning.iter().filter_map(|row| row.date))
+            .chain(balances.ending.iter().filter_map(|row| row.date))
+            .collect::<Vec<NaiveDate>>();
+        let resolve = |row: BalanceRow, date: Option<&NaiveDate>| {
+            let date =
+                row.date.or_else(|| date.copied()).ok_or_else(|| {
+                    Error::parse(row.number, "the statement has no dates")
+                })?;
+            Ok(StatementBalance {
+                date,
+                amount: row.amount,
+            })
+        };
+        statement.beginning_balances = balances
+            .beginning
+            .into_iter()
+            .map(|row| resolve(row, dates.iter().min()))
+            .collect::<Result<Vec<StatementBalance>>>()?;
+        statement.ending_balances = balances
+            .ending
+            .into_iter()
+            .map(|row| resolve(row, dates.iter().max()))
+            .collect::<Result<Vec<StatementBalance>>>()?;
+
+        statement.transactions.retain(|transaction| {
+            !INTERNAL_TRANSFERS.contains(&transaction.description.as_str())
+        });
+        for transaction in &mut statement.transactions {
+            transaction.description = self
+                .prefix
+                .replace_all(&transaction.description, "")
+                .to_string();
+        }
+        statement
+            .transactions
+            .sort_by_key(|transaction| transaction.date);
+        Ok(statement)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,99 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            generic.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Parser for statements exported as CSV.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use csv::ReaderBuilder;
+
+use crate::error::{Error, Result};
+use crate::statement::*;
+
+///////////////////////////////////////////////////////////////////////////////
+// CsvParser
+// A statement with a row for each transaction, having the date, description
+// and amount in the given columns (counted from zero). Exports like these
+// don't include balances.
+////
+
+pub struct CsvParser {
+    pub date: usize,
+    pub description: usize,
+    pub amount: usize,
+    pub has_headers: bool,
+}
+
+impl Default for CsvParser {
+    fn default() -> Self {
+        CsvParser {
+            date: 0,
+            description: 1,
+            amount: 2,
+            has_headers: true,
+        }
+    }
+}
+
+impl StatementParser for CsvParser {
+    fn parse(&self, text: &str) -> Result<Statement> {
+        let mut reader = ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .flexible(true)
+            .from_reader(text.as_bytes());
+        let mut transactions = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            let number = record
+                .position()
+                .map(|position| position.line() as usize)
+                .unwrap_or_default();
+            let column = |index: usize| {
+                record.get(index).ok_or_else(|| {
+                    Error::parse(number, format!("missing column {}", index))
+                })
+            };
+
+            let date = column(self.date)?;
+            let date = parse_date(date).ok_or_else(|| {
+                Error::parse(number, format!("invalid date {:?}", date))
+            })?;
+            let amount = column(self.amount)?;
+            let amount = parse_amount(amount).ok_or_else(|| {
+                Error::parse(number, format!("invalid amount {:?}", amount))
+            })?;
+            transactions.push(StatementTransaction {
+                date,
+                description: column(self.description)?.trim().to_string(),
+                amount,
+            });
+        }
+
+        transactions.sort_by_key(|transaction| transaction.date);
+        Ok(Statement {
+            transactions,
+            ..Default::default()
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,137 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            statement.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The contents of a statement, common to every format.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_models::Money;
+use chrono::NaiveDate;
+
+use crate::error::Result;
+
+// Formats of the dates in statements, in the order they're tried. Two-digit
+// years come first, since "%Y" would read "10/03/22" as the year 22.
+const DATE_FORMATS: &[&str] = &["%m/%d/%y", "%m/%d/%Y", "%Y-%m-%d"];
+
+// Parse a date in any of the formats that statements use.
+pub(crate) fn parse_date(text: &str) -> Option<NaiveDate> {
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text.trim(), format).ok())
+}
+
+// Parse an amount, e.g. "-$1,234.56" or "(45.10)". Some statements mark
+// debits with a trailing minus, which pdftotext renders as a soft hyphen.
+pub(crate) fn parse_amount(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (text, negative) = match text
+        .strip_suffix('\u{ad}')
+        .or_else(|| text.strip_suffix('-'))
+    {
+        Some(text) => (text, true),
+        None => (text, false),
+    };
+    let amount: i64 = text.parse::<Money>().ok()?.into();
+    Some(if negative { -amount } else { amount })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// StatementTransaction
+// One line of a statement. The amount is negative for money leaving the
+// account.
+////
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatementTransaction {
+    pub date: NaiveDate,
+    pub description: String,
+    pub amount: i64,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// StatementBalance
+// The balance of an account at the close of the date.
+////
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatementBalance {
+    pub date: NaiveDate,
+    pub amount: i64,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Statement
+// Everything read from a statement, which may cover several accounts at the
+// institution, e.g. savings and checking. Transactions are in order of date.
+////
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Statement {
+    pub transactions: Vec<StatementTransaction>,
+    pub beginning_balances: Vec<StatementBalance>,
+    pub ending_balances: Vec<StatementBalance>,
+}
+
+impl Statement {
+    // Every date that appears in the statement.
+    fn dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.transactions
+            .iter()
+            .map(|transaction| transaction.date)
+            .chain(self.beginning_balances.iter().map(|balance| balance.date))
+            .chain(self.ending_balances.iter().map(|balance| balance.date))
+    }
+
+    // The close of the statement period, i.e. the latest date in it.
+    pub fn end_date(&self) -> Option<NaiveDate> {
+        self.dates().max()
+    }
+
+    // The balance at the close of the statement period. The accounts of a
+    // statement are imported as one account, so this is the sum of their
+    // ending balances.
+    pub fn closing_balance(&self) -> Option<StatementBalance> {
+        if self.ending_balances.is_empty() {
+            return None;
+        }
+        Some(StatementBalance {
+            date: self.end_date()?,
+            amount: self
+                .ending_balances
+                .iter()
+                .map(|balance| balance.amount)
+                .sum(),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// StatementParser
+// Reads the text of a statement in one format.
+////
+
+pub trait StatementParser {
+    fn parse(&self, text: &str) -> Result<Statement>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
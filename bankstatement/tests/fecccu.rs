@@ -0,0 +1,119 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            fecccu.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Tests of the FECCCU parser against a sample statement, as
+//                  converted by pdftotext -layout.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use bankstatement::fecccu::FecccuParser;
+use bankstatement::generic::CsvParser;
+use bankstatement::*;
+use chrono::NaiveDate;
+
+// The sample has a savings and a checking account, a page break in the
+// checking account, the two-line header of the Check Recon column,
+// descriptions continued on following lines, undated balance rows, debits
+// marked by soft hyphens and transfers between the accounts.
+const SAMPLE: &str = include_str!("fixtures/fecccu.txt");
+
+// The transactions that the old fecccu.py script extracted from the sample.
+const EXPECTED: &str = include_str!("fixtures/fecccu.csv");
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2022, month, day).unwrap()
+}
+
+#[test]
+fn transactions_match_the_old_script() -> Result<()> {
+    let statement = FecccuParser::new().parse(SAMPLE)?;
+    let expected = CsvParser::default().parse(EXPECTED)?;
+    assert_eq!(expected.transactions, statement.transactions);
+    Ok(())
+}
+
+#[test]
+fn undated_balances_are_dated_by_the_statement_period() -> Result<()> {
+    let statement = FecccuParser::new().parse(SAMPLE)?;
+    assert_eq!(
+        vec![
+            StatementBalance {
+                date: date(10, 1),
+                amount: 50000,
+            },
+            StatementBalance {
+                date: date(10, 1),
+                amount: 123456,
+            },
+        ],
+        statement.beginning_balances
+    );
+    assert_eq!(
+        vec![
+            StatementBalance {
+                date: date(10, 31),
+                amount: 52512,
+            },
+            StatementBalance {
+                date: date(10, 31),
+                amount: 191790,
+            },
+        ],
+        statement.ending_balances
+    );
+    Ok(())
+}
+
+#[test]
+fn closing_balance_sums_the_accounts() -> Result<()> {
+    let statement = FecccuParser::new().parse(SAMPLE)?;
+    assert_eq!(
+        Some(StatementBalance {
+            date: date(10, 31),
+            amount: 52512 + 191790,
+        }),
+        statement.closing_balance()
+    );
+    Ok(())
+}
+
+#[test]
+fn csv_columns_are_configurable() -> Result<()> {
+    let parser = CsvParser {
+        date: 2,
+        description: 0,
+        amount: 1,
+        has_headers: false,
+    };
+    let statement = parser.parse("COFFEE,$4.50\u{ad},10/09/22\n")?;
+    assert_eq!(
+        vec![StatementTransaction {
+            date: date(10, 9),
+            description: "COFFEE".to_string(),
+            amount: -450,
+        }],
+        statement.transactions
+    );
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
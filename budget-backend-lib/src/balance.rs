@@ -0,0 +1,206 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            balance.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Initial balances, and the opening balance of each account
+//                  in each budget period.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_models::*;
+use chrono::{FixedOffset, Utc};
+use sea_orm::prelude::*;
+use sea_orm::sea_query::Query;
+use sea_orm::{ConnectionTrait, QueryOrder, Set, TransactionTrait};
+
+use crate::error::{Error, Result};
+use crate::prelude::*;
+
+// Set the initial balance of an account for a budget period, replacing any
+// initial balance that was already set.
+pub async fn set_initial_balance(
+    db: &sea_orm::DatabaseConnection,
+    balance: NewInitialBalance,
+) -> Result<InitialBalance> {
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
+    let txn = db.begin().await?;
+    let existing = InitialBalances::find()
+        .filter(initial_balances::Column::Account.eq(balance.account.clone()))
+        .filter(initial_balances::Column::Budget.eq(balance.budget))
+        .one(&txn)
+        .await?;
+    let id = match existing {
+        Some(existing) => {
+            let mut model: initial_balances::ActiveModel = existing.into();
+            model.balance = Set(balance.balance);
+            model.last_updated = Set(now);
+            model.update(&txn).await?.id
+        }
+        None => {
+            let model = initial_balances::ActiveModel {
+                account: Set(balance.account),
+                budget: Set(balance.budget),
+                balance: Set(balance.balance),
+                last_updated: Set(now),
+                ..Default::default()
+            };
+            InitialBalances::insert(model)
+                .exec_with_returning(&txn)
+                .await?
+                .id
+        }
+    };
+
+    // The new balance may introduce or settle an adjustment.
+    update_adjustments(&txn).await?;
+    let model = InitialBalances::find_by_id(id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| Error::not_found("initial balance", id))?;
+    txn.commit().await?;
+    Ok(model.into())
+}
+
+pub async fn list_initial_balances(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<InitialBalance>> {
+    Ok(InitialBalances::find()
+        .order_by_asc(initial_balances::Column::Budget)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|balance| balance.into())
+        .collect())
+}
+
+// Compute the opening balance of every account for every budget period, in
+// order of the start of the period. Each period accounts for the real
+// transactions up to the start of the next period, so that transactions which
+// fall between periods are not lost. Nothing is written; see
+// record_adjustments().
+pub async fn opening_balances(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<OpeningBalance>> {
+    compute_opening_balances(db).await
+}
+
+// Record the current adjustment of each explicit initial balance with it,
+// returning the initial balances whose adjustment changed. Adjustments are
+// also recorded whenever an initial balance is set.
+pub async fn record_adjustments(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<InitialBalance>> {
+    let txn = db.begin().await?;
+    let updated = update_adjustments(&txn).await?;
+    txn.commit().await?;
+    Ok(updated)
+}
+
+async fn compute_opening_balances<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<OpeningBalance>> {
+    let budgets = PeriodicBudgets::find()
+        .order_by_asc(periodic_budgets::Column::StartDate)
+        .all(db)
+        .await?;
+    let real = Query::select()
+        .column(real_transactions::Column::Transaction)
+        .from(RealTransactions)
+        .to_owned();
+    let transactions = Transactions::find()
+        .filter(transactions::Column::Id.in_subquery(real))
+        .all(db)
+        .await?;
+
+    let initial = InitialBalances::find().all(db).await?;
+    let mut explicit: HashMap<String, HashMap<i32, i64>> = HashMap::new();
+    for balance in &initial {
+        explicit
+            .entry(balance.account.clone())
+            .or_default()
+            .insert(balance.budget, balance.balance);
+    }
+
+    let mut balances = Vec::new();
+    for account in Accounts::find().all(db).await? {
+        let periods = budgets
+            .iter()
+            .enumerate()
+            .map(|(i, budget)| {
+                let next = budgets.get(i + 1).map(|next| next.start_date);
+                let net = transactions
+                    .iter()
+                    .filter(|t| t.account == account.name)
+                    .filter(|t| t.date >= budget.start_date)
+                    .filter(|t| match next {
+                        Some(next) => t.date < next,
+                        None => t.date <= budget.end_date,
+                    })
+                    .map(|t| t.amount)
+                    .sum();
+                (budget.id, net)
+            })
+            .collect::<Vec<(i32, i64)>>();
+        let explicit =
+            explicit.get(&account.name).cloned().unwrap_or_default();
+        balances.extend(budget_models::opening_balances(
+            &account.name,
+            &periods,
+            &explicit,
+        ));
+    }
+
+    Ok(balances)
+}
+
+// Update the adjustment recorded with each initial balance, where it changed.
+async fn update_adjustments<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<InitialBalance>> {
+    let balances = compute_opening_balances(db).await?;
+    let initial = InitialBalances::find().all(db).await?;
+    let adjustments = balances
+        .iter()
+        .map(|balance| {
+            (
+                (balance.account.as_str(), balance.budget),
+                balance.adjustment(),
+            )
+        })
+        .collect::<HashMap<(&str, i32), Option<i64>>>();
+    let mut updated = Vec::new();
+    for model in initial {
+        let adjustment = adjustments
+            .get(&(model.account.as_str(), model.budget))
+            .copied()
+            .flatten();
+        if model.adjustment != adjustment {
+            let mut model: initial_balances::ActiveModel = model.into();
+            model.adjustment = Set(adjustment);
+            updated.push(model.update(db).await?.into());
+        }
+    }
+    Ok(updated)
+}
+
+///////////////////////////////////////////////////////////////////////////////
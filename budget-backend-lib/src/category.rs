@@ -0,0 +1,216 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            category.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Operations on the tree of categories, and the line items
+//                  which belong to them.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_models::*;
+use sea_orm::prelude::*;
+use sea_orm::Set;
+
+use crate::currency::{account_currencies, CurrencyConverter};
+use crate::error::{Error, Result};
+use crate::prelude::*;
+
+// Create a category. Its parent, if any, must already exist.
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    category: Category,
+) -> Result<Category> {
+    let model = categories::ActiveModel {
+        name: Set(category.name),
+        parent: Set(category.parent),
+    };
+    Ok(Categories::insert(model)
+        .exec_with_returning(db)
+        .await?
+        .into())
+}
+
+pub async fn list(db: &sea_orm::DatabaseConnection) -> Result<Vec<Category>> {
+    Ok(Categories::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|category| category.into())
+        .collect())
+}
+
+pub async fn tree(db: &sea_orm::DatabaseConnection) -> Result<CategoryTree> {
+    Ok(CategoryTree::new(list(db).await?))
+}
+
+// Move a category (and its subtree) beneath a new parent, or to the root of
+// the forest. A category may not be moved beneath one of its descendants.
+pub async fn set_parent(
+    db: &sea_orm::DatabaseConnection,
+    name: &str,
+    parent: Option<String>,
+) -> Result<Category> {
+    if let Some(parent) = &parent {
+        let tree = tree(db).await?;
+        if parent == name
+            || tree
+                .ancestors(parent)
+                .iter()
+                .any(|ancestor| ancestor.name == name)
+        {
+            return Err(Error::category_cycle(name, parent));
+        }
+    }
+
+    let model = categories::ActiveModel {
+        name: Set(name.to_string()),
+        parent: Set(parent),
+    };
+    Ok(model.update(db).await?.into())
+}
+
+// The ancestors of the category, nearest first.
+pub async fn ancestors(
+    db: &sea_orm::DatabaseConnection,
+    name: &str,
+) -> Result<Vec<Category>> {
+    Ok(tree(db)
+        .await?
+        .ancestors(name)
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
+// Every category in the subtree below this category.
+pub async fn descendants(
+    db: &sea_orm::DatabaseConnection,
+    name: &str,
+) -> Result<Vec<Category>> {
+    Ok(tree(db)
+        .await?
+        .descendants(name)
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
+// Assign a line item to a category, or remove it from its category.
+pub async fn set_line_item_category(
+    db: &sea_orm::DatabaseConnection,
+    line_item: &str,
+    category: Option<String>,
+) -> Result<LineItem> {
+    let model = line_items::ActiveModel {
+        summary: Set(line_item.to_string()),
+        category: Set(category),
+    };
+    Ok(model.update(db).await?.into())
+}
+
+// The total of each category in the periodic budget, where the total of a
+// category includes the totals of every category in its subtree. Real
+// transactions which are not assigned to a line item are not included. Totals
+// are reported in the given currency, converting the amount of each leg from
+// the currency of its account.
+pub async fn rolled_up_totals(
+    db: &sea_orm::DatabaseConnection,
+    periodic_budget: i32,
+    transaction_type: TransactionType,
+    currency: Currency,
+) -> Result<HashMap<String, Money>> {
+    let categories: HashMap<String, String> = LineItems::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|line_item| {
+            line_item
+                .category
+                .map(|category| (line_item.summary, category))
+        })
+        .collect();
+
+    let allocations: Vec<(String, i32, i64)> = match transaction_type {
+        TransactionType::Real => RealTransactions::find()
+            .filter(
+                real_transactions::Column::PeriodicBudget
+                    .eq(Some(periodic_budget)),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|real| {
+                real.line_item.map(|line_item| {
+                    (line_item, real.transaction, real.amount)
+                })
+            })
+            .collect(),
+        TransactionType::Planned => PlannedTransactions::find()
+            .filter(
+                planned_transactions::Column::PeriodicBudget
+                    .eq(Some(periodic_budget)),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|planned| {
+                (planned.line_item, planned.transaction, planned.amount)
+            })
+            .collect(),
+    };
+
+    let ids = allocations
+        .iter()
+        .map(|(_, transaction, _)| *transaction)
+        .collect::<Vec<i32>>();
+    let legs: HashMap<i32, transactions::Model> = Transactions::find()
+        .filter(transactions::Column::Id.is_in(ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|transaction| (transaction.id, transaction))
+        .collect();
+    let currencies = account_currencies(db).await?;
+    let converter = CurrencyConverter::load(db).await?;
+
+    let mut totals: HashMap<String, Money> = HashMap::new();
+    for (line_item, transaction, amount) in allocations {
+        let (category, leg) =
+            match (categories.get(&line_item), legs.get(&transaction)) {
+                (Some(category), Some(leg)) => (category, leg),
+                _ => continue,
+            };
+        let money = Money::new(
+            amount,
+            currencies.get(&leg.account).copied().unwrap_or_default(),
+        );
+        *totals
+            .entry(category.clone())
+            .or_insert_with(|| Money::zero(currency)) +=
+            converter.convert(money, currency, leg.date.into())?;
+    }
+
+    Ok(tree(db).await?.roll_up(&totals))
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,7 @@
 //
 // CREATED:         07/04/2022
 //
-// LAST EDITED:     11/19/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -27,7 +27,7 @@
 
 use budget_models::models;
 use chrono::FixedOffset;
-use sea_orm::Set;
+use sea_orm::{DbErr, Set};
 
 use crate::entities::*;
 
@@ -38,6 +38,75 @@ impl Into<models::AccountType> for sea_orm_active_enums::Accounttype {
             Self::Saving => models::AccountType::Saving,
             Self::Credit => models::AccountType::Credit,
             Self::Loan => models::AccountType::Loan,
+            Self::Investment => models::AccountType::Investment,
+            Self::Cash => models::AccountType::Cash,
+            Self::Retirement => models::AccountType::Retirement,
+            Self::Hsa => models::AccountType::Hsa,
+            Self::Mortgage => models::AccountType::Mortgage,
+            Self::Other => models::AccountType::Other,
+        }
+    }
+}
+
+impl From<models::AccountType> for sea_orm_active_enums::Accounttype {
+    fn from(value: models::AccountType) -> Self {
+        match value {
+            models::AccountType::Checking => Self::Checking,
+            models::AccountType::Saving => Self::Saving,
+            models::AccountType::Credit => Self::Credit,
+            models::AccountType::Loan => Self::Loan,
+            models::AccountType::Investment => Self::Investment,
+            models::AccountType::Cash => Self::Cash,
+            models::AccountType::Retirement => Self::Retirement,
+            models::AccountType::Hsa => Self::Hsa,
+            models::AccountType::Mortgage => Self::Mortgage,
+            models::AccountType::Other => Self::Other,
+        }
+    }
+}
+
+impl Into<models::TransactionType> for sea_orm_active_enums::Transactiontype {
+    fn into(self) -> models::TransactionType {
+        match self {
+            Self::Real => models::TransactionType::Real,
+            Self::Planned => models::TransactionType::Planned,
+        }
+    }
+}
+
+impl From<models::TransactionType> for sea_orm_active_enums::Transactiontype {
+    fn from(value: models::TransactionType) -> Self {
+        match value {
+            models::TransactionType::Real => Self::Real,
+            models::TransactionType::Planned => Self::Planned,
+        }
+    }
+}
+
+impl Into<budget_models::Currency> for sea_orm_active_enums::Currency {
+    fn into(self) -> budget_models::Currency {
+        match self {
+            Self::Aud => budget_models::Currency::Aud,
+            Self::Cad => budget_models::Currency::Cad,
+            Self::Eur => budget_models::Currency::Eur,
+            Self::Gbp => budget_models::Currency::Gbp,
+            Self::Jpy => budget_models::Currency::Jpy,
+            Self::Mxn => budget_models::Currency::Mxn,
+            Self::Usd => budget_models::Currency::Usd,
+        }
+    }
+}
+
+impl From<budget_models::Currency> for sea_orm_active_enums::Currency {
+    fn from(value: budget_models::Currency) -> Self {
+        match value {
+            budget_models::Currency::Aud => Self::Aud,
+            budget_models::Currency::Cad => Self::Cad,
+            budget_models::Currency::Eur => Self::Eur,
+            budget_models::Currency::Gbp => Self::Gbp,
+            budget_models::Currency::Jpy => Self::Jpy,
+            budget_models::Currency::Mxn => Self::Mxn,
+            budget_models::Currency::Usd => Self::Usd,
         }
     }
 }
@@ -47,12 +116,217 @@ impl Into<models::Account> for accounts::Model {
         models::Account {
             name: self.name,
             account_type: self.account_type.into(),
+            currency: self.currency.into(),
             date_opened: self.date_opened.into(),
             date_closed: self.date_closed.map(|date| date.into()),
         }
     }
 }
 
+impl From<models::Account> for accounts::ActiveModel {
+    fn from(value: models::Account) -> Self {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        accounts::ActiveModel {
+            name: Set(value.name),
+            account_type: Set(value.account_type.into()),
+            currency: Set(value.currency.into()),
+            date_opened: Set(value.date_opened.with_timezone(&utc)),
+            date_closed: Set(value.date_closed.map(|d| d.with_timezone(&utc))),
+        }
+    }
+}
+
+impl Into<models::BalanceSnapshot> for balance_snapshots::Model {
+    fn into(self) -> models::BalanceSnapshot {
+        models::BalanceSnapshot {
+            id: self.id,
+            account: self.account,
+            date: self.date.into(),
+            amount: self.amount,
+        }
+    }
+}
+
+impl From<models::NewBalanceSnapshot> for balance_snapshots::ActiveModel {
+    fn from(value: models::NewBalanceSnapshot) -> Self {
+        balance_snapshots::ActiveModel {
+            account: Set(value.account),
+            date: Set(value
+                .date
+                .with_timezone(&FixedOffset::east_opt(0).unwrap())),
+            amount: Set(value.amount),
+            ..Default::default()
+        }
+    }
+}
+
+impl Into<models::ImportBatch> for import_batches::Model {
+    fn into(self) -> models::ImportBatch {
+        models::ImportBatch {
+            id: self.id,
+            filename: self.filename,
+            hash: self.hash,
+            imported_at: self.imported_at.into(),
+            transaction_type: self.transaction_type.into(),
+            row_count: self.row_count,
+        }
+    }
+}
+
+impl Into<models::LineItemRule> for line_item_rules::Model {
+    fn into(self) -> models::LineItemRule {
+        models::LineItemRule {
+            id: self.id,
+            priority: self.priority,
+            line_item: self.line_item,
+            account: self.account,
+            pattern: self.pattern,
+            contains: self.contains,
+            amount: self.amount,
+        }
+    }
+}
+
+impl From<models::NewLineItemRule> for line_item_rules::ActiveModel {
+    fn from(value: models::NewLineItemRule) -> Self {
+        line_item_rules::ActiveModel {
+            priority: Set(value.priority),
+            line_item: Set(value.line_item),
+            account: Set(value.account),
+            pattern: Set(value.pattern),
+            contains: Set(value.contains),
+            amount: Set(value.amount),
+            ..Default::default()
+        }
+    }
+}
+
+impl Into<models::Reconciliation> for reconciliations::Model {
+    fn into(self) -> models::Reconciliation {
+        models::Reconciliation {
+            id: self.id,
+            account: self.account,
+            snapshot: self.snapshot,
+            start_date: self.start_date.into(),
+            end_date: self.end_date.into(),
+            adjustment: self.adjustment,
+            reconciled_at: self.reconciled_at.into(),
+        }
+    }
+}
+
+impl Into<models::Category> for categories::Model {
+    fn into(self) -> models::Category {
+        models::Category {
+            name: self.name,
+            parent: self.parent,
+        }
+    }
+}
+
+impl Into<models::InitialBalance> for initial_balances::Model {
+    fn into(self) -> models::InitialBalance {
+        models::InitialBalance {
+            id: self.id,
+            account: self.account,
+            budget: self.budget,
+            balance: self.balance,
+            last_updated: self.last_updated.into(),
+            adjustment: self.adjustment,
+        }
+    }
+}
+
+impl Into<models::LineItem> for line_items::Model {
+    fn into(self) -> models::LineItem {
+        models::LineItem {
+            summary: self.summary,
+            category: self.category,
+        }
+    }
+}
+
+impl From<models::LineItem> for line_items::ActiveModel {
+    fn from(value: models::LineItem) -> Self {
+        line_items::ActiveModel {
+            summary: Set(value.summary),
+            category: Set(value.category),
+        }
+    }
+}
+
+impl Into<models::OneTimeBudgetStatus>
+    for sea_orm_active_enums::Onetimebudgetstatus
+{
+    fn into(self) -> models::OneTimeBudgetStatus {
+        match self {
+            Self::Active => models::OneTimeBudgetStatus::Active,
+            Self::Completed => models::OneTimeBudgetStatus::Completed,
+            Self::Cancelled => models::OneTimeBudgetStatus::Cancelled,
+        }
+    }
+}
+
+impl From<models::OneTimeBudgetStatus>
+    for sea_orm_active_enums::Onetimebudgetstatus
+{
+    fn from(value: models::OneTimeBudgetStatus) -> Self {
+        match value {
+            models::OneTimeBudgetStatus::Active => Self::Active,
+            models::OneTimeBudgetStatus::Completed => Self::Completed,
+            models::OneTimeBudgetStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl Into<models::OneTimeBudget> for one_time_budgets::Model {
+    fn into(self) -> models::OneTimeBudget {
+        models::OneTimeBudget {
+            id: self.id,
+            description: self.description,
+            target: self.target,
+            account: self.account,
+            start_date: self.start_date.map(|date| date.into()),
+            end_date: self.end_date.map(|date| date.into()),
+            status: self.status.into(),
+        }
+    }
+}
+
+impl From<models::NewOneTimeBudget> for one_time_budgets::ActiveModel {
+    fn from(value: models::NewOneTimeBudget) -> Self {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        one_time_budgets::ActiveModel {
+            description: Set(value.description),
+            target: Set(value.target),
+            account: Set(value.account),
+            start_date: Set(value.start_date.map(|d| d.with_timezone(&utc))),
+            end_date: Set(value.end_date.map(|d| d.with_timezone(&utc))),
+            ..Default::default()
+        }
+    }
+}
+
+impl Into<models::PayeeAlias> for payee_aliases::Model {
+    fn into(self) -> models::PayeeAlias {
+        models::PayeeAlias {
+            id: self.id,
+            payee: self.payee,
+            pattern: self.pattern,
+        }
+    }
+}
+
+impl From<models::NewPayeeAlias> for payee_aliases::ActiveModel {
+    fn from(value: models::NewPayeeAlias) -> Self {
+        payee_aliases::ActiveModel {
+            payee: Set(value.payee),
+            pattern: Set(value.pattern),
+            ..Default::default()
+        }
+    }
+}
+
 impl Into<models::PeriodicBudget> for periodic_budgets::Model {
     fn into(self) -> models::PeriodicBudget {
         models::PeriodicBudget {
@@ -63,6 +337,77 @@ impl Into<models::PeriodicBudget> for periodic_budgets::Model {
     }
 }
 
+impl From<models::NewPeriodicBudget> for periodic_budgets::ActiveModel {
+    fn from(value: models::NewPeriodicBudget) -> Self {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        periodic_budgets::ActiveModel {
+            start_date: Set(value.start_date.with_timezone(&utc)),
+            end_date: Set(value.end_date.with_timezone(&utc)),
+            ..Default::default()
+        }
+    }
+}
+
+impl Into<models::ExchangeRate> for exchange_rates::Model {
+    fn into(self) -> models::ExchangeRate {
+        // The column is NUMERIC(18, 8), so the mantissa always fits.
+        models::ExchangeRate {
+            id: self.id,
+            date: self.date.into(),
+            from: self.from_currency.into(),
+            to: self.to_currency.into(),
+            rate: self.rate.mantissa() as i64,
+            scale: self.rate.scale(),
+        }
+    }
+}
+
+// The rule is stored as JSON, which may not describe a valid rule if the row
+// was written by hand.
+impl TryInto<models::RecurringTransaction> for recurring_transactions::Model {
+    type Error = DbErr;
+    fn try_into(self) -> Result<models::RecurringTransaction, Self::Error> {
+        let rule = serde_json::from_value(self.rule).map_err(|e| {
+            DbErr::Custom(format!("invalid recurrence rule: {}", e))
+        })?;
+        Ok(models::RecurringTransaction {
+            id: self.id,
+            summary: self.summary,
+            from_account: self.from_account,
+            to_account: self.to_account,
+            amount: self.amount,
+            line_item: self.line_item,
+            rule,
+        })
+    }
+}
+
+impl From<models::NewRecurringTransaction>
+    for recurring_transactions::ActiveModel
+{
+    fn from(value: models::NewRecurringTransaction) -> Self {
+        // Serializing a RecurrenceRule to a JSON value cannot fail.
+        recurring_transactions::ActiveModel {
+            summary: Set(value.summary),
+            from_account: Set(value.from_account),
+            to_account: Set(value.to_account),
+            amount: Set(value.amount),
+            line_item: Set(value.line_item),
+            rule: Set(serde_json::to_value(value.rule).unwrap()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Into<models::Tag> for tags::Model {
+    fn into(self) -> models::Tag {
+        models::Tag {
+            id: self.id,
+            tag: self.tag,
+        }
+    }
+}
+
 impl Into<models::TransactionData> for transactions::Model {
     fn into(self) -> models::TransactionData {
         models::TransactionData {
@@ -71,7 +416,9 @@ impl Into<models::TransactionData> for transactions::Model {
             date: self.date.into(),
             account: self.account,
             amount: self.amount,
-            completed_by: self.completed_by,
+            series: self.series,
+            fingerprint: self.fingerprint,
+            payee: self.payee,
         }
     }
 }
@@ -80,11 +427,13 @@ impl From<models::NewTransactionData> for transactions::ActiveModel {
     fn from(value: models::NewTransactionData) -> Self {
         transactions::ActiveModel {
             summary: Set(value.summary),
-            amount: Set(value.amount.into()),
+            amount: Set(value.amount),
             account: Set(value.account),
             date: Set(value
                 .date
                 .with_timezone(&FixedOffset::east_opt(0).unwrap())),
+            fingerprint: Set(value.fingerprint),
+            payee: Set(value.payee),
             ..Default::default()
         }
     }
@@ -0,0 +1,135 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            currency.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Conversion of money between currencies.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_models::calculation::Calculation;
+use budget_models::models::ExchangeRate;
+use budget_models::total::BurnUpTotal;
+use budget_models::{Currency, Money};
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::*;
+use sea_orm::QueryOrder;
+
+use crate::error::{Error, Result};
+use crate::prelude::*;
+
+///////////////////////////////////////////////////////////////////////////////
+// CurrencyConverter
+//  Converts amounts between currencies using the most recent exchange rate
+//  on or before the date of the amount. If only the reverse rate is known, it
+//  is used inverted.
+////
+
+pub struct CurrencyConverter {
+    // Sorted by date, oldest first.
+    rates: Vec<ExchangeRate>,
+}
+
+impl CurrencyConverter {
+    pub fn new(mut rates: Vec<ExchangeRate>) -> Self {
+        rates.sort_by_key(|rate| rate.date);
+        Self { rates }
+    }
+
+    pub async fn load(db: &DatabaseConnection) -> Result<Self, DbErr> {
+        let rates = ExchangeRates::find()
+            .order_by_asc(exchange_rates::Column::Date)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|rate| rate.into())
+            .collect::<Vec<ExchangeRate>>();
+        Ok(Self::new(rates))
+    }
+
+    pub fn convert(
+        &self,
+        money: Money,
+        currency: Currency,
+        date: DateTime<Utc>,
+    ) -> Result<Money> {
+        let from = money.currency();
+        if from == currency || money.is_zero() {
+            return Ok(Money::new(money.amount(), currency));
+        }
+
+        let missing = || {
+            Error::missing_exchange_rate(
+                from,
+                currency,
+                budget_models::display::date(&date.into()),
+            )
+        };
+        self.rates
+            .iter()
+            .rev()
+            .filter(|rate| rate.date <= date)
+            .find_map(|rate| {
+                if rate.from == from && rate.to == currency {
+                    Some(rate.convert(money))
+                } else if rate.from == currency && rate.to == from {
+                    Some(rate.convert_inverse(money))
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .ok_or_else(missing)
+    }
+
+    // Calculate the burn-up total of a set of dated amounts, reported in the
+    // given currency.
+    pub fn burn_up<I>(
+        &self,
+        currency: Currency,
+        amounts: I,
+    ) -> Result<BurnUpTotal>
+    where
+        I: IntoIterator<Item = (Money, DateTime<Utc>)>,
+    {
+        let mut total = BurnUpTotal::new(currency);
+        for (money, date) in amounts {
+            total.apply(self.convert(money, currency, date)?);
+        }
+        Ok(total)
+    }
+}
+
+// The currency of every account, by name. Amounts of the ledger are stored
+// without a currency, and take on the currency of their account.
+pub async fn account_currencies(
+    db: &DatabaseConnection,
+) -> Result<HashMap<String, Currency>, DbErr> {
+    Ok(Accounts::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|account| (account.name, account.currency.into()))
+        .collect())
+}
+
+///////////////////////////////////////////////////////////////////////////////
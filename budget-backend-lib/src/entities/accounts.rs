@@ -1,6 +1,7 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.10.4
 
 use super::sea_orm_active_enums::Accounttype;
+use super::sea_orm_active_enums::Currency;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,7 @@ pub struct Model {
     pub account_type: Accounttype,
     pub date_opened: DateTimeWithTimeZone,
     pub date_closed: Option<DateTimeWithTimeZone>,
+    pub currency: Currency,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -14,6 +14,7 @@ pub struct Model {
     pub account: String,
     pub date: DateTimeWithTimeZone,
     pub amount: i64,
+    pub import_batch: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -26,6 +27,14 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Accounts,
+    #[sea_orm(
+        belongs_to = "super::import_batches::Entity",
+        from = "Column::ImportBatch",
+        to = "super::import_batches::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    ImportBatches,
 }
 
 impl Related<super::accounts::Entity> for Entity {
@@ -34,4 +43,10 @@ impl Related<super::accounts::Entity> for Entity {
     }
 }
 
+impl Related<super::import_batches::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ImportBatches.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -10,16 +10,32 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false, column_type = "Text")]
     pub summary: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub category: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::categories::Entity",
+        from = "Column::Category",
+        to = "super::categories::Column::Name",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Categories,
     #[sea_orm(has_many = "super::planned_transactions::Entity")]
     PlannedTransactions,
     #[sea_orm(has_many = "super::real_transactions::Entity")]
     RealTransactions,
 }
 
+impl Related<super::categories::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Categories.def()
+    }
+}
+
 impl Related<super::planned_transactions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PlannedTransactions.def()
@@ -4,9 +4,22 @@ pub mod prelude;
 
 pub mod accounts;
 pub mod balance_snapshots;
+pub mod categories;
+pub mod exchange_rates;
+pub mod import_batches;
+pub mod initial_balances;
+pub mod line_item_rules;
 pub mod line_items;
+pub mod one_time_budgets;
+pub mod payee_aliases;
+pub mod payees;
 pub mod periodic_budgets;
 pub mod planned_transactions;
 pub mod real_transactions;
+pub mod reconciliations;
+pub mod recurring_transactions;
 pub mod sea_orm_active_enums;
+pub mod tags;
+pub mod transaction_series;
+pub mod transaction_tags;
 pub mod transactions;
@@ -2,8 +2,21 @@
 
 pub use super::accounts::Entity as Accounts;
 pub use super::balance_snapshots::Entity as BalanceSnapshots;
+pub use super::categories::Entity as Categories;
+pub use super::exchange_rates::Entity as ExchangeRates;
+pub use super::import_batches::Entity as ImportBatches;
+pub use super::initial_balances::Entity as InitialBalances;
+pub use super::line_item_rules::Entity as LineItemRules;
 pub use super::line_items::Entity as LineItems;
+pub use super::one_time_budgets::Entity as OneTimeBudgets;
+pub use super::payee_aliases::Entity as PayeeAliases;
+pub use super::payees::Entity as Payees;
 pub use super::periodic_budgets::Entity as PeriodicBudgets;
 pub use super::planned_transactions::Entity as PlannedTransactions;
 pub use super::real_transactions::Entity as RealTransactions;
+pub use super::reconciliations::Entity as Reconciliations;
+pub use super::recurring_transactions::Entity as RecurringTransactions;
+pub use super::tags::Entity as Tags;
+pub use super::transaction_series::Entity as TransactionSeries;
+pub use super::transaction_tags::Entity as TransactionTags;
 pub use super::transactions::Entity as Transactions;
@@ -14,6 +14,8 @@ pub struct Model {
     #[sea_orm(column_type = "Text", nullable)]
     pub line_item: Option<String>,
     pub periodic_budget: Option<i32>,
+    pub one_time_budget: Option<i32>,
+    pub amount: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -26,6 +28,14 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     LineItems,
+    #[sea_orm(
+        belongs_to = "super::one_time_budgets::Entity",
+        from = "Column::OneTimeBudget",
+        to = "super::one_time_budgets::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    OneTimeBudgets,
     #[sea_orm(
         belongs_to = "super::periodic_budgets::Entity",
         from = "Column::PeriodicBudget",
@@ -50,6 +60,12 @@ impl Related<super::line_items::Entity> for Entity {
     }
 }
 
+impl Related<super::one_time_budgets::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OneTimeBudgets.def()
+    }
+}
+
 impl Related<super::periodic_budgets::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PeriodicBudgets.def()
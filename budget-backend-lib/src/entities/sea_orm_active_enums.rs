@@ -15,12 +15,91 @@ use serde::{Deserialize, Serialize};
 )]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "accounttype")]
 pub enum Accounttype {
+    #[sea_orm(string_value = "cash")]
+    Cash,
     #[sea_orm(string_value = "checking")]
     Checking,
     #[sea_orm(string_value = "credit")]
     Credit,
+    #[sea_orm(string_value = "hsa")]
+    Hsa,
+    #[sea_orm(string_value = "investment")]
+    Investment,
     #[sea_orm(string_value = "loan")]
     Loan,
+    #[sea_orm(string_value = "mortgage")]
+    Mortgage,
+    #[sea_orm(string_value = "other")]
+    Other,
+    #[sea_orm(string_value = "retirement")]
+    Retirement,
     #[sea_orm(string_value = "saving")]
     Saving,
 }
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "currency")]
+pub enum Currency {
+    #[sea_orm(string_value = "AUD")]
+    Aud,
+    #[sea_orm(string_value = "CAD")]
+    Cad,
+    #[sea_orm(string_value = "EUR")]
+    Eur,
+    #[sea_orm(string_value = "GBP")]
+    Gbp,
+    #[sea_orm(string_value = "JPY")]
+    Jpy,
+    #[sea_orm(string_value = "MXN")]
+    Mxn,
+    #[sea_orm(string_value = "USD")]
+    Usd,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "onetimebudgetstatus"
+)]
+pub enum Onetimebudgetstatus {
+    #[sea_orm(string_value = "active")]
+    Active,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+    #[sea_orm(string_value = "completed")]
+    Completed,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "transactiontype")]
+pub enum Transactiontype {
+    #[sea_orm(string_value = "planned")]
+    Planned,
+    #[sea_orm(string_value = "real")]
+    Real,
+}
@@ -16,7 +16,12 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub account: String,
     pub amount: i64,
-    pub completed_by: Option<i32>,
+    pub series: Option<i32>,
+    pub recurring_transaction: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable, unique)]
+    pub fingerprint: Option<String>,
+    pub import_batch: Option<i32>,
+    pub payee: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -29,18 +34,44 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Accounts,
+    #[sea_orm(
+        belongs_to = "super::import_batches::Entity",
+        from = "Column::ImportBatch",
+        to = "super::import_batches::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    ImportBatches,
+    #[sea_orm(
+        belongs_to = "super::payees::Entity",
+        from = "Column::Payee",
+        to = "super::payees::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Payees,
     #[sea_orm(has_many = "super::planned_transactions::Entity")]
     PlannedTransactions,
     #[sea_orm(has_many = "super::real_transactions::Entity")]
     RealTransactions,
     #[sea_orm(
-        belongs_to = "Entity",
-        from = "Column::CompletedBy",
-        to = "Column::Id",
+        belongs_to = "super::recurring_transactions::Entity",
+        from = "Column::RecurringTransaction",
+        to = "super::recurring_transactions::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    RecurringTransactions,
+    #[sea_orm(has_many = "super::transaction_tags::Entity")]
+    TransactionTags,
+    #[sea_orm(
+        belongs_to = "super::transaction_series::Entity",
+        from = "Column::Series",
+        to = "super::transaction_series::Column::Id",
         on_update = "NoAction",
         on_delete = "Cascade"
     )]
-    SelfRef,
+    TransactionSeries,
 }
 
 impl Related<super::accounts::Entity> for Entity {
@@ -49,6 +80,18 @@ impl Related<super::accounts::Entity> for Entity {
     }
 }
 
+impl Related<super::import_batches::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ImportBatches.def()
+    }
+}
+
+impl Related<super::payees::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Payees.def()
+    }
+}
+
 impl Related<super::planned_transactions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PlannedTransactions.def()
@@ -61,4 +104,22 @@ impl Related<super::real_transactions::Entity> for Entity {
     }
 }
 
+impl Related<super::recurring_transactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RecurringTransactions.def()
+    }
+}
+
+impl Related<super::transaction_tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionTags.def()
+    }
+}
+
+impl Related<super::transaction_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionSeries.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -7,7 +7,7 @@
 //
 // CREATED:         11/14/2022
 //
-// LAST EDITED:     11/19/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,50 +24,176 @@
 // limitations under the License.
 ////
 
-use std::error::Error;
+use budget_models::{Currency, ValidationError};
+use sea_orm::{sqlx, DbErr, RuntimeErr};
+use std::error;
 use std::fmt;
 
 ///////////////////////////////////////////////////////////////////////////////
-// MissingLineItemError
+// Error
+// Every error returned by this crate. Consumers can tell entities which don't
+// exist, invalid input and conflicts with the current state of the database
+// apart from failures of the database or of the configuration.
 ////
 
 #[derive(Debug)]
-pub struct MissingLineItemError(String);
-impl MissingLineItemError {
-    pub fn new<S: AsRef<str>>(line_item: S) -> Self {
-        Self(line_item.as_ref().to_string())
-    }
+pub enum Error {
+    // An entity doesn't exist, e.g. the line item "Groceries".
+    NotFound { entity: &'static str, key: String },
+
+    // The input violates the rules of the models.
+    Validation(ValidationError),
+
+    // The change conflicts with the current state of the database, e.g. a
+    // duplicate key, or a category which would become its own ancestor.
+    Conflict(String),
+
+    Database(DbErr),
+
+    // The application is misconfigured, e.g. a missing or malformed secret.
+    Configuration(String),
+
+    // One of a batch of records failed, so none of the batch was persisted.
+    FailedRecord { index: usize, error: Box<Error> },
 }
 
-impl Error for MissingLineItemError {}
-impl fmt::Display for MissingLineItemError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "line item {} does not exist", &self.0)
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn not_found<K: ToString>(entity: &'static str, key: K) -> Self {
+        Self::NotFound {
+            entity,
+            key: key.to_string(),
+        }
     }
-}
 
-///////////////////////////////////////////////////////////////////////////////
-// MissingBudgetError
-////
+    pub fn missing_exchange_rate<S: AsRef<str>>(
+        from: Currency,
+        to: Currency,
+        date: S,
+    ) -> Self {
+        Self::not_found(
+            "exchange rate",
+            format!("from {} to {} on or before {}", from, to, date.as_ref()),
+        )
+    }
 
-#[derive(Debug)]
-pub struct MissingBudgetError(String);
+    pub fn category_cycle<S: AsRef<str>>(category: S, parent: S) -> Self {
+        Self::Conflict(format!(
+            "category {} cannot have parent {}, which is one of its \
+             descendants",
+            category.as_ref(),
+            parent.as_ref()
+        ))
+    }
+
+    pub fn reconciled<S: AsRef<str>>(account: S, snapshot: i32) -> Self {
+        Self::Conflict(format!(
+            "transactions of account {} up to balance snapshot {} have been \
+             reconciled, and can't be changed",
+            account.as_ref(),
+            snapshot
+        ))
+    }
 
-impl MissingBudgetError {
-    pub fn new<S: AsRef<str>>(date: S) -> Self {
-        Self(date.as_ref().to_string())
+    pub fn earliest_snapshot<S: AsRef<str>>(
+        account: S,
+        snapshot: i32,
+    ) -> Self {
+        Self::Conflict(format!(
+            "balance snapshot {} is the earliest of account {}, so there is \
+             nothing to reconcile it against",
+            snapshot,
+            account.as_ref()
+        ))
+    }
+
+    pub fn unbalanced(snapshot: i32, delta: i64) -> Self {
+        Self::Conflict(format!(
+            "the interval ending at balance snapshot {} is off by {}",
+            snapshot, delta
+        ))
+    }
+
+    // The index of the record is counted from zero.
+    pub fn failed_record(index: usize, error: Error) -> Self {
+        Self::FailedRecord {
+            index,
+            error: Box::new(error),
+        }
+    }
+
+    // The error which caused this one, looking through failed records.
+    pub fn root(&self) -> &Error {
+        match self {
+            Self::FailedRecord { error, .. } => error.root(),
+            _ => self,
+        }
     }
 }
 
-impl Error for MissingBudgetError {}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Validation(e) => Some(e),
+            Self::Database(e) => Some(e),
+            Self::FailedRecord { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
 
-impl fmt::Display for MissingBudgetError {
+impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "no periodic budget exists which includes date {}",
-            &self.0
-        )
+        match self {
+            Self::NotFound { entity, key } => {
+                write!(f, "{} {} does not exist", entity, key)
+            }
+            Self::Validation(e) => write!(f, "{}", e),
+            Self::Conflict(message) => write!(f, "{}", message),
+            Self::Database(e) => write!(f, "database error: {}", e),
+            Self::Configuration(message) => {
+                write!(f, "configuration error: {}", message)
+            }
+            Self::FailedRecord { index, error } => {
+                write!(f, "record {} failed: {}", index + 1, error)
+            }
+        }
+    }
+}
+
+// Violations of database constraints (SQLSTATE class 23, e.g. unique, foreign
+// key, check and not-null violations) are conflicts with the current state of
+// the database, rather than failures of the database itself.
+impl From<DbErr> for Error {
+    fn from(value: DbErr) -> Self {
+        match sqlstate(&value) {
+            Some(code) if code.starts_with("23") => {
+                Self::Conflict(value.to_string())
+            }
+            _ => Self::Database(value),
+        }
+    }
+}
+
+// The SQLSTATE code reported by the database, if the error came from it.
+fn sqlstate(error: &DbErr) -> Option<String> {
+    let error = match error {
+        DbErr::Exec(RuntimeErr::SqlxError(error)) => error,
+        DbErr::Query(RuntimeErr::SqlxError(error)) => error,
+        _ => return None,
+    };
+    match error {
+        sqlx::Error::Database(error) => {
+            error.code().map(|code| code.into_owned())
+        }
+        _ => None,
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(value: ValidationError) -> Self {
+        Self::Validation(value)
     }
 }
 
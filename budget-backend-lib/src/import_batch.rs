@@ -0,0 +1,163 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            import_batch.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Records of imports, so that an import can be undone.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_models::*;
+use chrono::{FixedOffset, Utc};
+use sea_orm::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{QueryOrder, Set, TransactionTrait};
+
+use crate::error::{Error, Result};
+use crate::prelude::*;
+use crate::transaction;
+
+// Create the transactions and balance snapshots, and a batch which every leg
+// and snapshot refers to. Either the batch and all of the transactions and
+// snapshots are created, or nothing is. Invalid transactions are reported
+// with Error::FailedRecord.
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    batch: NewImportBatch,
+    transactions: Vec<NewTransaction>,
+    snapshots: Vec<NewBalanceSnapshot>,
+) -> Result<(ImportBatch, Vec<Transaction>, Vec<BalanceSnapshot>)> {
+    transaction::validate_many(db, &transactions).await?;
+
+    let txn = db.begin().await?;
+    let model = import_batches::ActiveModel {
+        filename: Set(batch.filename),
+        hash: Set(batch.hash),
+        imported_at: Set(
+            Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap())
+        ),
+        transaction_type: Set(batch.transaction_type.into()),
+        row_count: Set(transactions.len() as i32),
+        ..Default::default()
+    };
+    let model = ImportBatches::insert(model)
+        .exec_with_returning(&txn)
+        .await?;
+    let created = transaction::insert_many(&txn, transactions).await?;
+    let legs = created
+        .iter()
+        .flat_map(|transaction| transaction.series.legs())
+        .map(|leg| leg.id)
+        .collect::<Vec<i32>>();
+    Transactions::update_many()
+        .col_expr(transactions::Column::ImportBatch, Expr::value(model.id))
+        .filter(transactions::Column::Id.is_in(legs))
+        .exec(&txn)
+        .await?;
+
+    let mut recorded = Vec::new();
+    for snapshot in snapshots {
+        let mut snapshot: balance_snapshots::ActiveModel = snapshot.into();
+        snapshot.import_batch = Set(Some(model.id));
+        let snapshot = BalanceSnapshots::insert(snapshot)
+            .exec_with_returning(&txn)
+            .await?;
+        recorded.push(snapshot.into());
+    }
+    txn.commit().await?;
+    Ok((model.into(), created, recorded))
+}
+
+// List every import batch, most recent first.
+pub async fn list(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<ImportBatch>> {
+    Ok(ImportBatches::find()
+        .order_by_desc(import_batches::Column::ImportedAt)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|batch| batch.into())
+        .collect())
+}
+
+// Get an import batch, along with the transactions it created which still
+// exist.
+pub async fn get(
+    db: &sea_orm::DatabaseConnection,
+    id: i32,
+) -> Result<(ImportBatch, Vec<Transaction>)> {
+    let batch = ImportBatches::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| Error::not_found("import batch", id))?;
+    let legs = Transactions::find()
+        .filter(transactions::Column::ImportBatch.eq(id))
+        .order_by_asc(transactions::Column::Date)
+        .order_by_asc(transactions::Column::Id)
+        .all(db)
+        .await?;
+    let transactions = transaction::assemble(db, legs).await?;
+    Ok((batch.into(), transactions))
+}
+
+// Undo an import, removing exactly the transactions and balance snapshots it
+// created, and the batch itself. Fails without removing anything if any of
+// the transactions are in a reconciled period, or if any of the snapshots
+// have been reconciled. Returns the number of transactions removed.
+pub async fn undo(db: &sea_orm::DatabaseConnection, id: i32) -> Result<usize> {
+    let txn = db.begin().await?;
+    let batch = ImportBatches::find_by_id(id)
+        .one(&txn)
+        .await?
+        .ok_or_else(|| Error::not_found("import batch", id))?;
+    let legs = Transactions::find()
+        .filter(transactions::Column::ImportBatch.eq(batch.id))
+        .all(&txn)
+        .await?;
+    let snapshots = BalanceSnapshots::find()
+        .filter(balance_snapshots::Column::ImportBatch.eq(batch.id))
+        .all(&txn)
+        .await?;
+    if let Some(reconciliation) = Reconciliations::find()
+        .filter(
+            reconciliations::Column::Snapshot
+                .is_in(snapshots.iter().map(|snapshot| snapshot.id)),
+        )
+        .one(&txn)
+        .await?
+    {
+        return Err(Error::reconciled(
+            reconciliation.account,
+            reconciliation.snapshot,
+        ));
+    }
+
+    let count = transaction::remove(&txn, legs).await?;
+    BalanceSnapshots::delete_many()
+        .filter(balance_snapshots::Column::ImportBatch.eq(batch.id))
+        .exec(&txn)
+        .await?;
+    ImportBatches::delete_by_id(batch.id).exec(&txn).await?;
+    txn.commit().await?;
+    Ok(count)
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         09/18/2022
 //
-// LAST EDITED:     11/17/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,11 +24,24 @@
 // limitations under the License.
 ////
 
+pub mod balance;
+pub mod category;
 mod conversions;
+pub mod currency;
 pub mod entities;
 pub mod error;
+pub mod import_batch;
+pub mod line_item_rule;
+pub mod one_time_budget;
+pub mod payee;
+pub mod reconciliation;
+pub mod recurrence;
+pub mod repository;
 pub mod secret;
+pub mod suggestion;
+pub mod tag;
 pub mod transaction;
+pub mod validation;
 
 pub mod prelude;
 
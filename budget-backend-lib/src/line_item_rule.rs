@@ -0,0 +1,80 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            line_item_rule.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Rules for assigning line items to imported transactions.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_models::*;
+use sea_orm::prelude::*;
+use sea_orm::QueryOrder;
+
+use crate::error::{Error, Result};
+use crate::prelude::*;
+
+// Create a rule. A ValidationError is returned if the pattern isn't a valid
+// regular expression, or the line item doesn't exist.
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    rule: NewLineItemRule,
+) -> Result<LineItemRule> {
+    let mut violations = rule.violations();
+    let line_item = LineItems::find_by_id(rule.line_item.clone())
+        .one(db)
+        .await?;
+    if line_item.is_none() {
+        violations.push(Violation::MissingLineItem {
+            line_item: rule.line_item.clone(),
+        });
+    }
+    ValidationError::check(violations)?;
+
+    let model: line_item_rules::ActiveModel = rule.into();
+    Ok(LineItemRules::insert(model)
+        .exec_with_returning(db)
+        .await?
+        .into())
+}
+
+// List every rule, in the order that they're tried.
+pub async fn list(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<LineItemRule>> {
+    Ok(LineItemRules::find()
+        .order_by_asc(line_item_rules::Column::Priority)
+        .order_by_asc(line_item_rules::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|rule| rule.into())
+        .collect())
+}
+
+pub async fn delete(db: &sea_orm::DatabaseConnection, id: i32) -> Result<()> {
+    let result = LineItemRules::delete_by_id(id).exec(db).await?;
+    if result.rows_affected == 0 {
+        return Err(Error::not_found("line item rule", id));
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
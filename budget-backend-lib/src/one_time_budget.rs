@@ -0,0 +1,152 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            one_time_budget.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Operations on one-time budgets, such as projects and
+//                  sinking funds.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_models::*;
+use sea_orm::prelude::*;
+use sea_orm::{QueryOrder, Set};
+
+use crate::currency::{account_currencies, CurrencyConverter};
+use crate::error::Result;
+use crate::prelude::*;
+
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    budget: NewOneTimeBudget,
+) -> Result<OneTimeBudget> {
+    let model: one_time_budgets::ActiveModel = budget.into();
+    Ok(OneTimeBudgets::insert(model)
+        .exec_with_returning(db)
+        .await?
+        .into())
+}
+
+pub async fn get(
+    db: &sea_orm::DatabaseConnection,
+    id: i32,
+) -> Result<Option<OneTimeBudget>> {
+    Ok(OneTimeBudgets::find_by_id(id)
+        .one(db)
+        .await?
+        .map(|budget| budget.into()))
+}
+
+pub async fn list(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<OneTimeBudget>> {
+    Ok(OneTimeBudgets::find()
+        .order_by_asc(one_time_budgets::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|budget| budget.into())
+        .collect())
+}
+
+pub async fn set_status(
+    db: &sea_orm::DatabaseConnection,
+    id: i32,
+    status: OneTimeBudgetStatus,
+) -> Result<OneTimeBudget> {
+    let model = one_time_budgets::ActiveModel {
+        id: Set(id),
+        status: Set(status.into()),
+        ..Default::default()
+    };
+    Ok(model.update(db).await?.into())
+}
+
+// Calculate funding and spending of the budget from the real and planned
+// transactions attached to it. If the budget has an account, only the legs
+// of transactions on that account are counted, and progress is reported in
+// its currency. Otherwise, every leg is converted to the default currency.
+pub async fn progress(
+    db: &sea_orm::DatabaseConnection,
+    budget: &OneTimeBudget,
+) -> Result<OneTimeBudgetProgress> {
+    let real = RealTransactions::find()
+        .filter(real_transactions::Column::OneTimeBudget.eq(Some(budget.id)))
+        .all(db)
+        .await?;
+    let planned = PlannedTransactions::find()
+        .filter(
+            planned_transactions::Column::OneTimeBudget.eq(Some(budget.id)),
+        )
+        .all(db)
+        .await?;
+
+    let ids = real
+        .iter()
+        .map(|row| row.transaction)
+        .chain(planned.iter().map(|row| row.transaction))
+        .collect::<Vec<i32>>();
+    let legs: HashMap<i32, transactions::Model> = Transactions::find()
+        .filter(transactions::Column::Id.is_in(ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|transaction| (transaction.id, transaction))
+        .collect();
+    let currencies = account_currencies(db).await?;
+    let currency = budget
+        .account
+        .as_ref()
+        .and_then(|account| currencies.get(account).copied())
+        .unwrap_or_default();
+    let converter = CurrencyConverter::load(db).await?;
+
+    // The amount of the leg in the currency of the budget, or None if the
+    // leg isn't counted.
+    let convert = |transaction: i32, amount: i64| {
+        let leg = legs.get(&transaction)?;
+        if budget.account.iter().any(|account| *account != leg.account) {
+            return None;
+        }
+        let money = Money::new(
+            amount,
+            currencies.get(&leg.account).copied().unwrap_or_default(),
+        );
+        Some(converter.convert(money, currency, leg.date.into()))
+    };
+    let real = real
+        .iter()
+        .filter_map(|row| convert(row.transaction, row.amount))
+        .collect::<Result<Vec<Money>>>()?;
+    let planned = planned
+        .iter()
+        .filter_map(|row| convert(row.transaction, row.amount))
+        .collect::<Result<Vec<Money>>>()?;
+
+    Ok(OneTimeBudgetProgress::new(
+        Money::new(budget.target, currency),
+        real,
+        planned,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,233 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            payee.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Payees, their aliases, and the normalization of
+//                  transactions to payees.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_models::normalization;
+use budget_models::*;
+use sea_orm::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{QueryOrder, Set, TransactionTrait};
+
+use crate::error::{Error, Result};
+use crate::prelude::*;
+
+// Attach to each payee its aliases, in the order they were created.
+fn assemble(
+    payees: Vec<payees::Model>,
+    aliases: Vec<payee_aliases::Model>,
+) -> Vec<Payee> {
+    let mut aliases_of: HashMap<i32, Vec<PayeeAlias>> = HashMap::new();
+    for alias in aliases {
+        aliases_of
+            .entry(alias.payee)
+            .or_default()
+            .push(alias.into());
+    }
+    payees
+        .into_iter()
+        .map(|payee| Payee {
+            aliases: aliases_of.remove(&payee.id).unwrap_or_default(),
+            id: payee.id,
+            name: payee.name,
+        })
+        .collect()
+}
+
+async fn with_aliases(
+    db: &sea_orm::DatabaseConnection,
+    payee: payees::Model,
+) -> Result<Payee> {
+    let aliases = PayeeAliases::find()
+        .filter(payee_aliases::Column::Payee.eq(payee.id))
+        .order_by_asc(payee_aliases::Column::Id)
+        .all(db)
+        .await?;
+    Ok(assemble(vec![payee], aliases).remove(0))
+}
+
+// Create a payee, along with its aliases. A ValidationError is returned if
+// any alias isn't a valid regular expression.
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    payee: NewPayee,
+) -> Result<Payee> {
+    ValidationError::check(payee.violations())?;
+
+    let txn = db.begin().await?;
+    let model = payees::ActiveModel {
+        name: Set(payee.name),
+        ..Default::default()
+    };
+    let model = Payees::insert(model).exec_with_returning(&txn).await?;
+    let mut aliases = Vec::new();
+    for pattern in payee.aliases {
+        let alias: payee_aliases::ActiveModel = NewPayeeAlias {
+            payee: model.id,
+            pattern,
+        }
+        .into();
+        aliases.push(
+            PayeeAliases::insert(alias)
+                .exec_with_returning(&txn)
+                .await?,
+        );
+    }
+    txn.commit().await?;
+    Ok(assemble(vec![model], aliases).remove(0))
+}
+
+// List every payee, in order of name.
+pub async fn list(db: &sea_orm::DatabaseConnection) -> Result<Vec<Payee>> {
+    let payees = Payees::find()
+        .order_by_asc(payees::Column::Name)
+        .all(db)
+        .await?;
+    let aliases = PayeeAliases::find()
+        .order_by_asc(payee_aliases::Column::Id)
+        .all(db)
+        .await?;
+    Ok(assemble(payees, aliases))
+}
+
+pub async fn get(db: &sea_orm::DatabaseConnection, id: i32) -> Result<Payee> {
+    let payee = Payees::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| Error::not_found("payee", id))?;
+    with_aliases(db, payee).await
+}
+
+// Get a payee by its name.
+pub async fn find(
+    db: &sea_orm::DatabaseConnection,
+    name: &str,
+) -> Result<Payee> {
+    let payee = Payees::find()
+        .filter(payees::Column::Name.eq(name))
+        .one(db)
+        .await?
+        .ok_or_else(|| Error::not_found("payee", name))?;
+    with_aliases(db, payee).await
+}
+
+// Add an alias to a payee. A ValidationError is returned if the pattern isn't
+// a valid regular expression.
+pub async fn add_alias(
+    db: &sea_orm::DatabaseConnection,
+    alias: NewPayeeAlias,
+) -> Result<PayeeAlias> {
+    ValidationError::check(alias.violations())?;
+    let model: payee_aliases::ActiveModel = alias.into();
+    Ok(PayeeAliases::insert(model)
+        .exec_with_returning(db)
+        .await?
+        .into())
+}
+
+pub async fn delete_alias(
+    db: &sea_orm::DatabaseConnection,
+    id: i32,
+) -> Result<()> {
+    let result = PayeeAliases::delete_by_id(id).exec(db).await?;
+    if result.rows_affected == 0 {
+        return Err(Error::not_found("payee alias", id));
+    }
+    Ok(())
+}
+
+// Merge one payee into another: its transactions and aliases are moved to the
+// other payee, and it's deleted. Returns the payee that remains.
+pub async fn merge(
+    db: &sea_orm::DatabaseConnection,
+    from: i32,
+    into: i32,
+) -> Result<Payee> {
+    if from == into {
+        return Err(Error::Conflict(format!(
+            "payee {} can't be merged into itself",
+            from
+        )));
+    }
+
+    let txn = db.begin().await?;
+    for id in [from, into] {
+        Payees::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| Error::not_found("payee", id))?;
+    }
+    Transactions::update_many()
+        .col_expr(transactions::Column::Payee, Expr::value(into))
+        .filter(transactions::Column::Payee.eq(from))
+        .exec(&txn)
+        .await?;
+    PayeeAliases::update_many()
+        .col_expr(payee_aliases::Column::Payee, Expr::value(into))
+        .filter(payee_aliases::Column::Payee.eq(from))
+        .exec(&txn)
+        .await?;
+    Payees::delete_by_id(from).exec(&txn).await?;
+    txn.commit().await?;
+    get(db, into).await
+}
+
+// Assign a payee to every transaction which doesn't have one yet, by the
+// aliases of the payees. This is done for imported transactions as they're
+// imported, so it's only needed after adding payees or aliases. Returns the
+// number of legs which were assigned a payee.
+pub async fn normalize(db: &sea_orm::DatabaseConnection) -> Result<usize> {
+    let payees = normalization::Payees::new(list(db).await?).map_err(|e| {
+        Error::Conflict(format!("payee alias is not a valid pattern: {}", e))
+    })?;
+    let legs = Transactions::find()
+        .filter(transactions::Column::Payee.is_null())
+        .all(db)
+        .await?;
+
+    let mut assigned: HashMap<i32, Vec<i32>> = HashMap::new();
+    for leg in legs {
+        if let Some(payee) = payees.find(&leg.summary) {
+            assigned.entry(payee.id).or_default().push(leg.id);
+        }
+    }
+
+    let txn = db.begin().await?;
+    let mut count = 0;
+    for (payee, legs) in assigned {
+        count += legs.len();
+        Transactions::update_many()
+            .col_expr(transactions::Column::Payee, Expr::value(payee))
+            .filter(transactions::Column::Id.is_in(legs))
+            .exec(&txn)
+            .await?;
+    }
+    txn.commit().await?;
+    Ok(count)
+}
+
+///////////////////////////////////////////////////////////////////////////////
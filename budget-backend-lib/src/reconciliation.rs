@@ -0,0 +1,288 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            reconciliation.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Reconciliation of accounts against their balance snapshots.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashSet;
+
+use budget_models::*;
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use sea_orm::prelude::*;
+use sea_orm::sea_query::Query;
+use sea_orm::{ConnectionTrait, QueryOrder, Set, TransactionTrait};
+
+use crate::error::{Error, Result};
+use crate::prelude::*;
+use crate::{transaction, validation};
+
+// The summary of transactions recorded to account for the delta of an
+// interval.
+pub const ADJUSTMENT_SUMMARY: &str = "Reconciliation adjustment";
+
+// Balance snapshots of the account, or of every account, in order of date.
+async fn find_snapshots<C: ConnectionTrait>(
+    db: &C,
+    account: Option<&str>,
+) -> Result<Vec<BalanceSnapshot>, DbErr> {
+    let mut query = BalanceSnapshots::find();
+    if let Some(account) = account {
+        query = query.filter(balance_snapshots::Column::Account.eq(account));
+    }
+    Ok(query
+        .order_by_asc(balance_snapshots::Column::Date)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|snapshot| snapshot.into())
+        .collect())
+}
+
+// Legs of real transactions on the account, or on every account. Planned
+// transactions never affect the balance reported by the institution.
+async fn find_real_legs<C: ConnectionTrait>(
+    db: &C,
+    account: Option<&str>,
+) -> Result<Vec<TransactionData>, DbErr> {
+    let real = Query::select()
+        .column(real_transactions::Column::Transaction)
+        .from(RealTransactions)
+        .to_owned();
+    let mut query = Transactions::find()
+        .filter(transactions::Column::Id.in_subquery(real));
+    if let Some(account) = account {
+        query = query.filter(transactions::Column::Account.eq(account));
+    }
+    Ok(query
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|leg| leg.into())
+        .collect())
+}
+
+// The interval which ends at the snapshot, and the legs of its account.
+async fn find_interval<C: ConnectionTrait>(
+    db: &C,
+    snapshot: i32,
+) -> Result<(SnapshotInterval, Vec<TransactionData>)> {
+    let account = BalanceSnapshots::find_by_id(snapshot)
+        .one(db)
+        .await?
+        .ok_or_else(|| Error::not_found("balance snapshot", snapshot))?
+        .account;
+    let snapshots = find_snapshots(db, Some(&account)).await?;
+    let legs = find_real_legs(db, Some(&account)).await?;
+    let interval = snapshot_intervals(&account, &snapshots, &legs)
+        .into_iter()
+        .find(|interval| interval.snapshot == snapshot)
+        .ok_or_else(|| Error::earliest_snapshot(&account, snapshot))?;
+    Ok((interval, legs))
+}
+
+async fn insert_reconciliation<C: ConnectionTrait>(
+    db: &C,
+    interval: &SnapshotInterval,
+    adjustment: Option<i32>,
+) -> Result<Reconciliation> {
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let model = reconciliations::ActiveModel {
+        account: Set(interval.account.clone()),
+        snapshot: Set(interval.snapshot),
+        start_date: Set(interval.start_date.with_timezone(&utc)),
+        end_date: Set(interval.end_date.with_timezone(&utc)),
+        adjustment: Set(adjustment),
+        reconciled_at: Set(Utc::now().with_timezone(&utc)),
+        ..Default::default()
+    };
+    let model = Reconciliations::insert(model)
+        .exec_with_returning(db)
+        .await?;
+    Ok(model.into())
+}
+
+// Fail with a conflict if any of the legs, given by account and date, fall
+// in a reconciled interval.
+pub(crate) async fn check_unlocked<C: ConnectionTrait>(
+    db: &C,
+    legs: &[(String, DateTime<Utc>)],
+) -> Result<()> {
+    let accounts = legs
+        .iter()
+        .map(|(account, _)| account.clone())
+        .collect::<HashSet<String>>();
+    let reconciliations = Reconciliations::find()
+        .filter(reconciliations::Column::Account.is_in(accounts))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.into())
+        .collect::<Vec<Reconciliation>>();
+    for (account, date) in legs {
+        if let Some(locked) = reconciliations
+            .iter()
+            .find(|reconciliation| reconciliation.locks(account, date))
+        {
+            return Err(Error::reconciled(account, locked.snapshot));
+        }
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+// Every interval between consecutive balance snapshots of every account.
+pub async fn intervals(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<SnapshotInterval>> {
+    let snapshots = find_snapshots(db, None).await?;
+    let legs = find_real_legs(db, None).await?;
+    let accounts = snapshots
+        .iter()
+        .map(|snapshot| snapshot.account.as_str())
+        .collect::<HashSet<&str>>();
+    let mut intervals = accounts
+        .into_iter()
+        .flat_map(|account| snapshot_intervals(account, &snapshots, &legs))
+        .collect::<Vec<SnapshotInterval>>();
+    intervals.sort_by(|a, b| {
+        (&a.account, a.end_date).cmp(&(&b.account, b.end_date))
+    });
+    Ok(intervals)
+}
+
+// Every interval which doesn't balance, with suggestions for its cause.
+pub async fn discrepancies(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<Discrepancy>> {
+    let snapshots = find_snapshots(db, None).await?;
+    let legs = find_real_legs(db, None).await?;
+    let accounts = snapshots
+        .iter()
+        .map(|snapshot| snapshot.account.as_str())
+        .collect::<HashSet<&str>>();
+    let mut discrepancies = accounts
+        .into_iter()
+        .flat_map(|account| {
+            budget_models::discrepancies(account, &snapshots, &legs)
+        })
+        .collect::<Vec<Discrepancy>>();
+    discrepancies.sort_by(|a, b| {
+        (&a.interval.account, a.interval.end_date)
+            .cmp(&(&b.interval.account, b.interval.end_date))
+    });
+    Ok(discrepancies)
+}
+
+pub async fn list(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<Reconciliation>> {
+    Ok(Reconciliations::find()
+        .order_by_asc(reconciliations::Column::Account)
+        .order_by_asc(reconciliations::Column::EndDate)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.into())
+        .collect())
+}
+
+// Mark the interval ending at the snapshot as reconciled, locking the
+// transactions in it against edits. The interval must balance; use adjust to
+// accept a delta instead.
+pub async fn reconcile(
+    db: &sea_orm::DatabaseConnection,
+    snapshot: i32,
+) -> Result<Reconciliation> {
+    let txn = db.begin().await?;
+    let (interval, _) = find_interval(&txn, snapshot).await?;
+    if interval.delta() != 0 {
+        return Err(Error::unbalanced(snapshot, interval.delta()));
+    }
+    let reconciliation = insert_reconciliation(&txn, &interval, None).await?;
+    txn.commit().await?;
+    Ok(reconciliation)
+}
+
+// Accept the delta of the interval ending at the snapshot: record a real
+// transaction for the delta at the end of the interval, and mark the
+// interval as reconciled.
+pub async fn adjust(
+    db: &sea_orm::DatabaseConnection,
+    snapshot: i32,
+) -> Result<Reconciliation> {
+    let (interval, _) = find_interval(db, snapshot).await?;
+    if interval.delta() == 0 {
+        return reconcile(db, snapshot).await;
+    }
+
+    let adjustment = NewTransaction {
+        metadata: TransactionTypedMetadata::Real(None),
+        series: NewTransactionSeries::Single(NewTransactionData {
+            summary: ADJUSTMENT_SUMMARY.to_string(),
+            account: interval.account.clone(),
+            amount: interval.delta(),
+            date: interval.end_date - Duration::seconds(1),
+            fingerprint: None,
+            payee: None,
+        }),
+    };
+    validation::validate(db, &adjustment).await?;
+
+    // The delta is recalculated in the transaction, in case the interval
+    // changed after it was read above.
+    let txn = db.begin().await?;
+    let (current, _) = find_interval(&txn, snapshot).await?;
+    if current.delta() != interval.delta() {
+        return Err(Error::Conflict(format!(
+            "the interval ending at balance snapshot {} changed while it was \
+             being adjusted",
+            snapshot
+        )));
+    }
+    let adjustment = transaction::insert(&txn, adjustment).await?;
+    let reconciliation =
+        insert_reconciliation(&txn, &interval, Some(adjustment.id)).await?;
+    txn.commit().await?;
+    Ok(reconciliation)
+}
+
+// Unlock the interval ending at the snapshot. Any adjustment that was
+// recorded for it is kept.
+pub async fn unreconcile(
+    db: &sea_orm::DatabaseConnection,
+    snapshot: i32,
+) -> Result<()> {
+    let result = Reconciliations::delete_many()
+        .filter(reconciliations::Column::Snapshot.eq(snapshot))
+        .exec(db)
+        .await?;
+    if result.rows_affected == 0 {
+        return Err(Error::not_found("reconciliation of snapshot", snapshot));
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
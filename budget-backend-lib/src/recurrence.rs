@@ -0,0 +1,167 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            recurrence.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Generation of planned transactions from recurring
+//                  transaction schedules.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use budget_models::*;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{QueryOrder, Set, TransactionTrait};
+
+use crate::currency::account_currencies;
+use crate::error::Result;
+use crate::prelude::*;
+use crate::{transaction, validation};
+
+// Create a recurring transaction schedule.
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    recurring: NewRecurringTransaction,
+) -> Result<RecurringTransaction> {
+    let model: recurring_transactions::ActiveModel = recurring.into();
+    let model = RecurringTransactions::insert(model)
+        .exec_with_returning(db)
+        .await?;
+    Ok(model.try_into()?)
+}
+
+// List all recurring transaction schedules.
+pub async fn list(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<RecurringTransaction>> {
+    RecurringTransactions::find()
+        .order_by_asc(recurring_transactions::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| Ok(model.try_into()?))
+        .collect()
+}
+
+// Generate planned transactions for every occurrence of the schedule which
+// falls within the periodic budget. Occurrences which have already been
+// generated are skipped, so this is safe to run repeatedly. Returns the
+// planned transactions that were created.
+pub async fn generate(
+    db: &sea_orm::DatabaseConnection,
+    recurring: &RecurringTransaction,
+    budget: &PeriodicBudget,
+) -> Result<Vec<Transaction>> {
+    let existing: HashSet<DateTime<Utc>> = Transactions::find()
+        .filter(
+            transactions::Column::RecurringTransaction.eq(Some(recurring.id)),
+        )
+        .filter(transactions::Column::Date.gte(budget.start_date))
+        .filter(transactions::Column::Date.lte(budget.end_date))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.date.into())
+        .collect();
+
+    // The amount of a schedule is in the currency of its accounts.
+    let currencies = account_currencies(db).await?;
+    let currency = recurring
+        .from_account
+        .iter()
+        .chain(recurring.to_account.iter())
+        .find_map(|account| currencies.get(account).copied())
+        .unwrap_or_default();
+
+    let mut created = Vec::new();
+    let occurrences = recurring
+        .rule
+        .occurrences_between(budget.start_date, budget.end_date);
+    for date in occurrences {
+        if existing.contains(&date) {
+            continue;
+        }
+
+        let new_transaction: NewTransaction = recurring
+            .instantiate(date, budget.id, currency)
+            .try_into()?;
+        validation::validate(db, &new_transaction).await?;
+        let txn = db.begin().await?;
+        let result = transaction::insert(&txn, new_transaction).await?;
+
+        // Mark every leg as belonging to this schedule, in the same database
+        // transaction, so the occurrence can't be generated twice.
+        let legs = match &result.series {
+            TransactionSeries::Single(data) => vec![data.id],
+            TransactionSeries::Linked(legs) => {
+                legs.iter().map(|leg| leg.id).collect()
+            }
+        };
+        Transactions::update_many()
+            .col_expr(
+                transactions::Column::RecurringTransaction,
+                Expr::value(recurring.id),
+            )
+            .filter(transactions::Column::Id.is_in(legs))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        created.push(result);
+    }
+
+    Ok(created)
+}
+
+// Generate planned transactions for every schedule in every periodic budget.
+pub async fn generate_all(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<Vec<Transaction>> {
+    let budgets: Vec<PeriodicBudget> = PeriodicBudgets::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|budget| budget.into())
+        .collect();
+
+    let mut created = Vec::new();
+    for recurring in list(db).await? {
+        for budget in &budgets {
+            created.extend(generate(db, &recurring, budget).await?);
+        }
+    }
+    Ok(created)
+}
+
+// Stop a schedule. Transactions already generated from it are kept, but are
+// no longer associated with the schedule.
+pub async fn delete(db: &sea_orm::DatabaseConnection, id: i32) -> Result<()> {
+    let model = recurring_transactions::ActiveModel {
+        id: Set(id),
+        ..Default::default()
+    };
+    RecurringTransactions::delete(model).exec(db).await?;
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,193 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            repository.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Storage abstraction over the models of the application.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use async_trait::async_trait;
+use budget_models::*;
+use chrono::Duration;
+
+use crate::error::Result;
+
+mod database;
+mod memory;
+
+pub use database::DatabaseRepository;
+pub use memory::MemoryRepository;
+
+///////////////////////////////////////////////////////////////////////////////
+// Repository
+// Storage for the models that the business logic works on. Logic written
+// against this trait, rather than a database connection, can run against
+// either implementation. Transactions in reconciled periods can't be
+// created, changed or removed; doing so fails with Error::Conflict.
+////
+
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn accounts(&self) -> Result<Vec<Account>>;
+    async fn create_account(&self, account: Account) -> Result<Account>;
+
+    async fn line_items(&self) -> Result<Vec<LineItem>>;
+    async fn create_line_item(&self, line_item: LineItem) -> Result<LineItem>;
+
+    // Every line item rule, in the order that they're tried.
+    async fn line_item_rules(&self) -> Result<Vec<LineItemRule>>;
+    async fn create_line_item_rule(
+        &self,
+        rule: NewLineItemRule,
+    ) -> Result<LineItemRule>;
+
+    // Every payee, in order of name.
+    async fn payees(&self) -> Result<Vec<Payee>>;
+    async fn create_payee(&self, payee: NewPayee) -> Result<Payee>;
+
+    async fn periodic_budgets(&self) -> Result<Vec<PeriodicBudget>>;
+    async fn create_periodic_budget(
+        &self,
+        budget: NewPeriodicBudget,
+    ) -> Result<PeriodicBudget>;
+
+    async fn one_time_budgets(&self) -> Result<Vec<OneTimeBudget>>;
+    async fn create_one_time_budget(
+        &self,
+        budget: NewOneTimeBudget,
+    ) -> Result<OneTimeBudget>;
+
+    // Get a transaction by the id of any of its legs.
+    async fn transaction(&self, id: i32) -> Result<Transaction>;
+
+    // The transactions selected by the filter, in order of date.
+    async fn transactions(
+        &self,
+        filter: &TransactionFilter,
+    ) -> Result<Vec<Transaction>>;
+
+    // Either all of the transactions are created, or none are. Invalid
+    // transactions are reported with Error::FailedRecord.
+    async fn create_transactions(
+        &self,
+        transactions: Vec<NewTransaction>,
+    ) -> Result<Vec<Transaction>>;
+
+    // Like create_transactions, but also records the import batch that the
+    // transactions came from, and the balance snapshots of the import, e.g.
+    // the closing balance of a statement. Every leg and snapshot refers to
+    // the batch.
+    async fn import_transactions(
+        &self,
+        batch: NewImportBatch,
+        transactions: Vec<NewTransaction>,
+        snapshots: Vec<NewBalanceSnapshot>,
+    ) -> Result<(ImportBatch, Vec<BalanceSnapshot>)>;
+
+    // Get an import batch, along with the transactions it created which
+    // still exist.
+    async fn import_batch(
+        &self,
+        id: i32,
+    ) -> Result<(ImportBatch, Vec<Transaction>)>;
+
+    // Remove exactly the transactions and snapshots that the import created,
+    // and the batch itself. Returns the number of transactions removed.
+    async fn undo_import(&self, id: i32) -> Result<usize>;
+
+    async fn update_transaction(
+        &self,
+        id: i32,
+        transaction: NewTransaction,
+    ) -> Result<Transaction>;
+
+    async fn delete_transaction(&self, id: i32) -> Result<()>;
+
+    // Every balance snapshot, in order of date.
+    async fn balance_snapshots(&self) -> Result<Vec<BalanceSnapshot>>;
+    async fn create_balance_snapshot(
+        &self,
+        snapshot: NewBalanceSnapshot,
+    ) -> Result<BalanceSnapshot>;
+
+    // Every reconciliation, by account and then by date.
+    async fn reconciliations(&self) -> Result<Vec<Reconciliation>>;
+
+    // Mark the interval ending at the snapshot as reconciled, locking the
+    // transactions in it. The interval must balance.
+    async fn reconcile(&self, snapshot: i32) -> Result<Reconciliation>;
+
+    // Every exchange rate, in order of date.
+    async fn exchange_rates(&self) -> Result<Vec<ExchangeRate>>;
+
+    // Everything a transaction may refer to. When validating many
+    // transactions, load the context once and reuse it.
+    async fn validation_context(&self) -> Result<ValidationContext> {
+        Ok(ValidationContext::new(
+            self.accounts().await?,
+            self.line_items()
+                .await?
+                .into_iter()
+                .map(|line_item| line_item.summary)
+                .collect(),
+            self.periodic_budgets().await?,
+            self.one_time_budgets().await?,
+        ))
+    }
+
+    // Match each transaction to be imported in the batch against the
+    // transactions of the same type that are already recorded, to find
+    // re-imported and possibly duplicated transactions. See match_import().
+    async fn match_imports(
+        &self,
+        batch: &NewImportBatch,
+        transactions: &[NewTransaction],
+    ) -> Result<Vec<ImportMatch>> {
+        let dates = transactions
+            .iter()
+            .flat_map(|transaction| transaction.series.legs())
+            .map(|leg| leg.date)
+            .collect::<Vec<_>>();
+        let (start, end) = match (dates.iter().min(), dates.iter().max()) {
+            (Some(start), Some(end)) => (*start, *end),
+            _ => return Ok(Vec::new()),
+        };
+
+        let window = Duration::days(FUZZY_MATCH_DAYS);
+        let filter = TransactionFilter {
+            transaction_type: Some(batch.transaction_type.clone()),
+            start_date: Some(start - window),
+            end_date: Some(end + window),
+            ..Default::default()
+        };
+        let existing = self.transactions(&filter).await?;
+        let legs = existing
+            .iter()
+            .flat_map(|transaction| transaction.series.legs())
+            .collect::<Vec<&TransactionData>>();
+        Ok(transactions
+            .iter()
+            .map(|transaction| match_import(transaction, &legs))
+            .collect())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
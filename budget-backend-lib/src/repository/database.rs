@@ -0,0 +1,246 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            database.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Repository backed by the Postgres database.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use async_trait::async_trait;
+use budget_models::*;
+use sea_orm::prelude::*;
+use sea_orm::{DatabaseConnection, QueryOrder};
+
+use super::Repository;
+use crate::error::Result;
+use crate::prelude::*;
+use crate::{
+    import_batch, line_item_rule, one_time_budget, payee, reconciliation,
+    transaction,
+};
+
+///////////////////////////////////////////////////////////////////////////////
+// DatabaseRepository
+// The repository backed by the Postgres database.
+////
+
+#[derive(Clone)]
+pub struct DatabaseRepository {
+    db: DatabaseConnection,
+}
+
+impl DatabaseRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub fn connection(&self) -> &DatabaseConnection {
+        &self.db
+    }
+}
+
+#[async_trait]
+impl Repository for DatabaseRepository {
+    async fn accounts(&self) -> Result<Vec<Account>> {
+        Ok(Accounts::find()
+            .order_by_asc(accounts::Column::Name)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|account| account.into())
+            .collect())
+    }
+
+    async fn create_account(&self, account: Account) -> Result<Account> {
+        let model: accounts::ActiveModel = account.into();
+        Ok(Accounts::insert(model)
+            .exec_with_returning(&self.db)
+            .await?
+            .into())
+    }
+
+    async fn line_items(&self) -> Result<Vec<LineItem>> {
+        Ok(LineItems::find()
+            .order_by_asc(line_items::Column::Summary)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|line_item| line_item.into())
+            .collect())
+    }
+
+    async fn create_line_item(&self, line_item: LineItem) -> Result<LineItem> {
+        let model: line_items::ActiveModel = line_item.into();
+        Ok(LineItems::insert(model)
+            .exec_with_returning(&self.db)
+            .await?
+            .into())
+    }
+
+    async fn line_item_rules(&self) -> Result<Vec<LineItemRule>> {
+        line_item_rule::list(&self.db).await
+    }
+
+    async fn create_line_item_rule(
+        &self,
+        rule: NewLineItemRule,
+    ) -> Result<LineItemRule> {
+        line_item_rule::create(&self.db, rule).await
+    }
+
+    async fn payees(&self) -> Result<Vec<Payee>> {
+        payee::list(&self.db).await
+    }
+
+    async fn create_payee(&self, payee: NewPayee) -> Result<Payee> {
+        payee::create(&self.db, payee).await
+    }
+
+    async fn periodic_budgets(&self) -> Result<Vec<PeriodicBudget>> {
+        Ok(PeriodicBudgets::find()
+            .order_by_asc(periodic_budgets::Column::StartDate)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|budget| budget.into())
+            .collect())
+    }
+
+    async fn create_periodic_budget(
+        &self,
+        budget: NewPeriodicBudget,
+    ) -> Result<PeriodicBudget> {
+        let model: periodic_budgets::ActiveModel = budget.into();
+        Ok(PeriodicBudgets::insert(model)
+            .exec_with_returning(&self.db)
+            .await?
+            .into())
+    }
+
+    async fn one_time_budgets(&self) -> Result<Vec<OneTimeBudget>> {
+        one_time_budget::list(&self.db).await
+    }
+
+    async fn create_one_time_budget(
+        &self,
+        budget: NewOneTimeBudget,
+    ) -> Result<OneTimeBudget> {
+        one_time_budget::create(&self.db, budget).await
+    }
+
+    async fn transaction(&self, id: i32) -> Result<Transaction> {
+        transaction::get(&self.db, id).await
+    }
+
+    async fn transactions(
+        &self,
+        filter: &TransactionFilter,
+    ) -> Result<Vec<Transaction>> {
+        transaction::list(&self.db, filter).await
+    }
+
+    async fn create_transactions(
+        &self,
+        transactions: Vec<NewTransaction>,
+    ) -> Result<Vec<Transaction>> {
+        transaction::create_many(&self.db, transactions).await
+    }
+
+    async fn import_transactions(
+        &self,
+        batch: NewImportBatch,
+        transactions: Vec<NewTransaction>,
+        snapshots: Vec<NewBalanceSnapshot>,
+    ) -> Result<(ImportBatch, Vec<BalanceSnapshot>)> {
+        let (batch, _, snapshots) =
+            import_batch::create(&self.db, batch, transactions, snapshots)
+                .await?;
+        Ok((batch, snapshots))
+    }
+
+    async fn import_batch(
+        &self,
+        id: i32,
+    ) -> Result<(ImportBatch, Vec<Transaction>)> {
+        import_batch::get(&self.db, id).await
+    }
+
+    async fn undo_import(&self, id: i32) -> Result<usize> {
+        import_batch::undo(&self.db, id).await
+    }
+
+    async fn update_transaction(
+        &self,
+        id: i32,
+        transaction: NewTransaction,
+    ) -> Result<Transaction> {
+        transaction::update(&self.db, id, transaction).await
+    }
+
+    async fn delete_transaction(&self, id: i32) -> Result<()> {
+        transaction::delete(&self.db, id).await
+    }
+
+    async fn balance_snapshots(&self) -> Result<Vec<BalanceSnapshot>> {
+        Ok(BalanceSnapshots::find()
+            .order_by_asc(balance_snapshots::Column::Date)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|snapshot| snapshot.into())
+            .collect())
+    }
+
+    async fn create_balance_snapshot(
+        &self,
+        snapshot: NewBalanceSnapshot,
+    ) -> Result<BalanceSnapshot> {
+        let model: balance_snapshots::ActiveModel = snapshot.into();
+        Ok(BalanceSnapshots::insert(model)
+            .exec_with_returning(&self.db)
+            .await?
+            .into())
+    }
+
+    async fn reconciliations(&self) -> Result<Vec<Reconciliation>> {
+        reconciliation::list(&self.db).await
+    }
+
+    async fn reconcile(&self, snapshot: i32) -> Result<Reconciliation> {
+        reconciliation::reconcile(&self.db, snapshot).await
+    }
+
+    async fn exchange_rates(&self) -> Result<Vec<ExchangeRate>> {
+        Ok(ExchangeRates::find()
+            .order_by_asc(exchange_rates::Column::Date)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|rate| rate.into())
+            .collect())
+    }
+
+    async fn validation_context(&self) -> Result<ValidationContext> {
+        Ok(crate::validation::load_context(&self.db).await?)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
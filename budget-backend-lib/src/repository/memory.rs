@@ -0,0 +1,968 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            memory.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Repository which keeps everything in memory.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use budget_models::*;
+use chrono::{DateTime, Utc};
+
+use super::Repository;
+use crate::error::{Error, Result};
+
+///////////////////////////////////////////////////////////////////////////////
+// State
+// Ids are drawn from one counter shared by every model, so they're unique
+// across the whole repository.
+////
+
+#[derive(Default)]
+struct State {
+    accounts: Vec<Account>,
+    line_items: Vec<LineItem>,
+    line_item_rules: Vec<LineItemRule>,
+    payees: Vec<Payee>,
+    periodic_budgets: Vec<PeriodicBudget>,
+    one_time_budgets: Vec<OneTimeBudget>,
+    transactions: Vec<Transaction>,
+    balance_snapshots: Vec<BalanceSnapshot>,
+    exchange_rates: Vec<ExchangeRate>,
+    import_batches: Vec<ImportBatch>,
+    reconciliations: Vec<Reconciliation>,
+    // The import batch of every imported leg and snapshot, by their ids.
+    imported_legs: HashMap<i32, i32>,
+    imported_snapshots: HashMap<i32, i32>,
+    last_id: i32,
+}
+
+impl State {
+    fn next_id(&mut self) -> i32 {
+        self.last_id += 1;
+        self.last_id
+    }
+
+    // The index of the transaction having a leg with this id.
+    fn find_transaction(&self, id: i32) -> Result<usize> {
+        self.transactions
+            .iter()
+            .position(|transaction| {
+                transaction.series.legs().iter().any(|leg| leg.id == id)
+            })
+            .ok_or_else(|| Error::not_found("transaction", id))
+    }
+
+    // The indices of the transactions created by the import batch, in order.
+    fn find_imported(&self, batch: i32) -> Vec<usize> {
+        self.transactions
+            .iter()
+            .enumerate()
+            .filter(|(_, transaction)| {
+                transaction
+                    .series
+                    .legs()
+                    .iter()
+                    .any(|leg| self.imported_legs.get(&leg.id) == Some(&batch))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    // Fail with a conflict if any of the legs, given by account and date,
+    // fall in a reconciled interval.
+    fn check_unlocked(&self, legs: &[(String, DateTime<Utc>)]) -> Result<()> {
+        for (account, date) in legs {
+            if let Some(locked) = self
+                .reconciliations
+                .iter()
+                .find(|reconciliation| reconciliation.locks(account, date))
+            {
+                return Err(Error::reconciled(account, locked.snapshot));
+            }
+        }
+        Ok(())
+    }
+
+    // Validate and store the transactions, failing with Error::FailedRecord
+    // for the first one which is invalid or in a reconciled period.
+    fn insert(
+        &mut self,
+        context: &ValidationContext,
+        transactions: Vec<NewTransaction>,
+    ) -> Result<Vec<Transaction>> {
+        for (index, transaction) in transactions.iter().enumerate() {
+            context
+                .validate(transaction)
+                .map_err(Error::from)
+                .and_then(|_| self.check_unlocked(&new_leg_dates(transaction)))
+                .map_err(|e| Error::failed_record(index, e))?;
+        }
+        Ok(transactions
+            .into_iter()
+            .map(|transaction| self.store(transaction, Vec::new(), None))
+            .collect())
+    }
+
+    // Remove the transaction at the index, forgetting any import of its legs.
+    fn remove(&mut self, index: usize) {
+        let transaction = self.transactions.remove(index);
+        for leg in transaction.series.legs() {
+            self.imported_legs.remove(&leg.id);
+        }
+    }
+
+    // Store a transaction. The legs take the given ids first, and a linked
+    // series keeps the given series id, if there is one.
+    fn store(
+        &mut self,
+        transaction: NewTransaction,
+        ids: Vec<i32>,
+        series: Option<i32>,
+    ) -> Transaction {
+        let NewTransaction {
+            metadata,
+            series: new_series,
+        } = transaction;
+        let series = match &new_series {
+            NewTransactionSeries::Single(_) => None,
+            NewTransactionSeries::Linked(_) => {
+                Some(series.unwrap_or_else(|| self.next_id()))
+            }
+        };
+
+        let mut ids = ids.into_iter();
+        let mut legs = Vec::new();
+        for leg in new_series.legs() {
+            legs.push(TransactionData {
+                id: ids.next().unwrap_or_else(|| self.next_id()),
+                summary: leg.summary.clone(),
+                account: leg.account.clone(),
+                amount: leg.amount,
+                series,
+                date: leg.date,
+                fingerprint: leg.fingerprint.clone(),
+                payee: leg.payee,
+            });
+        }
+
+        let transaction = Transaction {
+            id: legs.last().map(|leg| leg.id).unwrap_or_default(),
+            metadata,
+            series: match new_series {
+                NewTransactionSeries::Single(_) => {
+                    TransactionSeries::Single(legs.remove(0))
+                }
+                NewTransactionSeries::Linked(_) => {
+                    TransactionSeries::Linked(legs)
+                }
+            },
+        };
+        self.transactions.push(transaction.clone());
+        transaction
+    }
+}
+
+fn leg_dates(transaction: &Transaction) -> Vec<(String, DateTime<Utc>)> {
+    transaction
+        .series
+        .legs()
+        .into_iter()
+        .map(|leg| (leg.account.clone(), leg.date))
+        .collect()
+}
+
+fn new_leg_dates(
+    transaction: &NewTransaction,
+) -> Vec<(String, DateTime<Utc>)> {
+    transaction
+        .series
+        .legs()
+        .into_iter()
+        .map(|leg| (leg.account.clone(), leg.date))
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MemoryRepository
+// A repository which keeps everything in memory, for running the business
+// logic without a database. Transactions are validated and locked by
+// reconciliations the same way as in the database, but tag filters are not
+// supported.
+////
+
+#[derive(Default)]
+pub struct MemoryRepository {
+    state: Mutex<State>,
+}
+
+impl MemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Exchange rates can't be created through the Repository trait.
+    pub fn add_exchange_rate(&self, rate: ExchangeRate) {
+        self.state().exchange_rates.push(rate);
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl Repository for MemoryRepository {
+    async fn accounts(&self) -> Result<Vec<Account>> {
+        let mut accounts = self.state().accounts.clone();
+        accounts.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(accounts)
+    }
+
+    async fn create_account(&self, account: Account) -> Result<Account> {
+        let mut state = self.state();
+        if state.accounts.iter().any(|a| a.name == account.name) {
+            return Err(Error::Conflict(format!(
+                "account {} already exists",
+                &account.name
+            )));
+        }
+        state.accounts.push(account.clone());
+        Ok(account)
+    }
+
+    async fn line_items(&self) -> Result<Vec<LineItem>> {
+        let mut line_items = self.state().line_items.clone();
+        line_items.sort_by(|a, b| a.summary.cmp(&b.summary));
+        Ok(line_items)
+    }
+
+    async fn create_line_item(&self, line_item: LineItem) -> Result<LineItem> {
+        let mut state = self.state();
+        if state
+            .line_items
+            .iter()
+            .any(|l| l.summary == line_item.summary)
+        {
+            return Err(Error::Conflict(format!(
+                "line item {} already exists",
+                &line_item.summary
+            )));
+        }
+        state.line_items.push(line_item.clone());
+        Ok(line_item)
+    }
+
+    async fn line_item_rules(&self) -> Result<Vec<LineItemRule>> {
+        let mut rules = self.state().line_item_rules.clone();
+        rules.sort_by_key(|rule| (rule.priority, rule.id));
+        Ok(rules)
+    }
+
+    async fn create_line_item_rule(
+        &self,
+        rule: NewLineItemRule,
+    ) -> Result<LineItemRule> {
+        let mut state = self.state();
+        let mut violations = rule.violations();
+        if !state.line_items.iter().any(|l| l.summary == rule.line_item) {
+            violations.push(Violation::MissingLineItem {
+                line_item: rule.line_item.clone(),
+            });
+        }
+        ValidationError::check(violations)?;
+
+        let rule = LineItemRule {
+            id: state.next_id(),
+            priority: rule.priority,
+            line_item: rule.line_item,
+            account: rule.account,
+            pattern: rule.pattern,
+            contains: rule.contains,
+            amount: rule.amount,
+        };
+        state.line_item_rules.push(rule.clone());
+        Ok(rule)
+    }
+
+    async fn payees(&self) -> Result<Vec<Payee>> {
+        let mut payees = self.state().payees.clone();
+        payees.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(payees)
+    }
+
+    async fn create_payee(&self, payee: NewPayee) -> Result<Payee> {
+        let mut state = self.state();
+        ValidationError::check(payee.violations())?;
+        if state.payees.iter().any(|p| p.name == payee.name) {
+            return Err(Error::Conflict(format!(
+                "payee {} already exists",
+                &payee.name
+            )));
+        }
+
+        let id = state.next_id();
+        let aliases = payee
+            .aliases
+            .into_iter()
+            .map(|pattern| PayeeAlias {
+                id: state.next_id(),
+                payee: id,
+                pattern,
+            })
+            .collect();
+        let payee = Payee {
+            id,
+            name: payee.name,
+            aliases,
+        };
+        state.payees.push(payee.clone());
+        Ok(payee)
+    }
+
+    async fn periodic_budgets(&self) -> Result<Vec<PeriodicBudget>> {
+        let mut budgets = self.state().periodic_budgets.clone();
+        budgets.sort_by_key(|budget| budget.start_date);
+        Ok(budgets)
+    }
+
+    async fn create_periodic_budget(
+        &self,
+        budget: NewPeriodicBudget,
+    ) -> Result<PeriodicBudget> {
+        let mut state = self.state();
+        let budget = PeriodicBudget {
+            id: state.next_id(),
+            start_date: budget.start_date,
+            end_date: budget.end_date,
+        };
+        state.periodic_budgets.push(budget.clone());
+        Ok(budget)
+    }
+
+    async fn one_time_budgets(&self) -> Result<Vec<OneTimeBudget>> {
+        Ok(self.state().one_time_budgets.clone())
+    }
+
+    async fn create_one_time_budget(
+        &self,
+        budget: NewOneTimeBudget,
+    ) -> Result<OneTimeBudget> {
+        let mut state = self.state();
+        let budget = OneTimeBudget {
+            id: state.next_id(),
+            description: budget.description,
+            target: budget.target,
+            account: budget.account,
+            start_date: budget.start_date,
+            end_date: budget.end_date,
+            status: OneTimeBudgetStatus::default(),
+        };
+        state.one_time_budgets.push(budget.clone());
+        Ok(budget)
+    }
+
+    async fn transaction(&self, id: i32) -> Result<Transaction> {
+        let state = self.state();
+        let index = state.find_transaction(id)?;
+        Ok(state.transactions[index].clone())
+    }
+
+    async fn transactions(
+        &self,
+        filter: &TransactionFilter,
+    ) -> Result<Vec<Transaction>> {
+        if filter.tags.is_some() {
+            return Err(Error::Configuration(
+                "tag filters are not supported in memory".to_string(),
+            ));
+        }
+
+        let mut transactions = self
+            .state()
+            .transactions
+            .iter()
+            .filter(|transaction| filter.matches(transaction))
+            .cloned()
+            .collect::<Vec<Transaction>>();
+        transactions.sort_by_key(|transaction| {
+            (transaction.series.legs()[0].date, transaction.id)
+        });
+        Ok(transactions)
+    }
+
+    async fn create_transactions(
+        &self,
+        transactions: Vec<NewTransaction>,
+    ) -> Result<Vec<Transaction>> {
+        let context = self.validation_context().await?;
+        self.state().insert(&context, transactions)
+    }
+
+    async fn import_transactions(
+        &self,
+        batch: NewImportBatch,
+        transactions: Vec<NewTransaction>,
+        snapshots: Vec<NewBalanceSnapshot>,
+    ) -> Result<(ImportBatch, Vec<BalanceSnapshot>)> {
+        let context = self.validation_context().await?;
+        let mut state = self.state();
+        let row_count = transactions.len() as i32;
+        let created = state.insert(&context, transactions)?;
+        let batch = ImportBatch {
+            id: state.next_id(),
+            filename: batch.filename,
+            hash: batch.hash,
+            imported_at: Utc::now(),
+            transaction_type: batch.transaction_type,
+            row_count,
+        };
+        for leg in created.iter().flat_map(|t| t.series.legs()) {
+            state.imported_legs.insert(leg.id, batch.id);
+        }
+
+        let mut recorded = Vec::new();
+        for snapshot in snapshots {
+            let snapshot = BalanceSnapshot {
+                id: state.next_id(),
+                account: snapshot.account,
+                date: snapshot.date,
+                amount: snapshot.amount,
+            };
+            state.imported_snapshots.insert(snapshot.id, batch.id);
+            state.balance_snapshots.push(snapshot.clone());
+            recorded.push(snapshot);
+        }
+        state.import_batches.push(batch.clone());
+        Ok((batch, recorded))
+    }
+
+    async fn import_batch(
+        &self,
+        id: i32,
+    ) -> Result<(ImportBatch, Vec<Transaction>)> {
+        let state = self.state();
+        let batch = state
+            .import_batches
+            .iter()
+            .find(|batch| batch.id == id)
+            .cloned()
+            .ok_or_else(|| Error::not_found("import batch", id))?;
+        let mut transactions = state
+            .find_imported(id)
+            .into_iter()
+            .map(|index| state.transactions[index].clone())
+            .collect::<Vec<Transaction>>();
+        transactions.sort_by_key(|transaction| {
+            (transaction.series.legs()[0].date, transaction.id)
+        });
+        Ok((batch, transactions))
+    }
+
+    async fn undo_import(&self, id: i32) -> Result<usize> {
+        let mut state = self.state();
+        if !state.import_batches.iter().any(|batch| batch.id == id) {
+            return Err(Error::not_found("import batch", id));
+        }
+        let imported = state.find_imported(id);
+        let dates = imported
+            .iter()
+            .flat_map(|index| leg_dates(&state.transactions[*index]))
+            .collect::<Vec<(String, DateTime<Utc>)>>();
+        state.check_unlocked(&dates)?;
+        if let Some(reconciliation) = state
+            .reconciliations
+            .iter()
+            .find(|r| state.imported_snapshots.get(&r.snapshot) == Some(&id))
+        {
+            return Err(Error::reconciled(
+                &reconciliation.account,
+                reconciliation.snapshot,
+            ));
+        }
+
+        for index in imported.iter().rev() {
+            state.remove(*index);
+        }
+        let State {
+            balance_snapshots,
+            imported_snapshots,
+            ..
+        } = &mut *state;
+        balance_snapshots.retain(|snapshot| {
+            imported_snapshots.get(&snapshot.id) != Some(&id)
+        });
+        imported_snapshots.retain(|_, batch| *batch != id);
+        state.import_batches.retain(|batch| batch.id != id);
+        Ok(imported.len())
+    }
+
+    async fn update_transaction(
+        &self,
+        id: i32,
+        transaction: NewTransaction,
+    ) -> Result<Transaction> {
+        self.validation_context().await?.validate(&transaction)?;
+        let mut state = self.state();
+        let index = state.find_transaction(id)?;
+
+        // Neither the old nor the new legs may fall in a reconciled period.
+        let mut dates = leg_dates(&state.transactions[index]);
+        dates.extend(new_leg_dates(&transaction));
+        state.check_unlocked(&dates)?;
+        let existing = state.transactions.remove(index);
+        let legs = existing.series.legs();
+        let ids = legs.iter().map(|leg| leg.id).collect();
+        let series = legs.first().and_then(|leg| leg.series);
+        Ok(state.store(transaction, ids, series))
+    }
+
+    async fn delete_transaction(&self, id: i32) -> Result<()> {
+        let mut state = self.state();
+        let index = state.find_transaction(id)?;
+        state.check_unlocked(&leg_dates(&state.transactions[index]))?;
+        state.remove(index);
+        Ok(())
+    }
+
+    async fn balance_snapshots(&self) -> Result<Vec<BalanceSnapshot>> {
+        let mut snapshots = self.state().balance_snapshots.clone();
+        snapshots.sort_by_key(|snapshot| snapshot.date);
+        Ok(snapshots)
+    }
+
+    async fn create_balance_snapshot(
+        &self,
+        snapshot: NewBalanceSnapshot,
+    ) -> Result<BalanceSnapshot> {
+        let mut state = self.state();
+        let snapshot = BalanceSnapshot {
+            id: state.next_id(),
+            account: snapshot.account,
+            date: snapshot.date,
+            amount: snapshot.amount,
+        };
+        state.balance_snapshots.push(snapshot.clone());
+        Ok(snapshot)
+    }
+
+    async fn reconciliations(&self) -> Result<Vec<Reconciliation>> {
+        let mut reconciliations = self.state().reconciliations.clone();
+        reconciliations.sort_by(|a, b| {
+            (&a.account, a.end_date).cmp(&(&b.account, b.end_date))
+        });
+        Ok(reconciliations)
+    }
+
+    async fn reconcile(&self, snapshot: i32) -> Result<Reconciliation> {
+        let mut state = self.state();
+        let account = state
+            .balance_snapshots
+            .iter()
+            .find(|s| s.id == snapshot)
+            .map(|s| s.account.clone())
+            .ok_or_else(|| Error::not_found("balance snapshot", snapshot))?;
+        if state.reconciliations.iter().any(|r| r.snapshot == snapshot) {
+            return Err(Error::Conflict(format!(
+                "balance snapshot {} is already reconciled",
+                snapshot
+            )));
+        }
+
+        // Planned transactions never affect the balance reported by the
+        // institution.
+        let legs = state
+            .transactions
+            .iter()
+            .filter(|transaction| {
+                matches!(
+                    transaction.metadata,
+                    TransactionTypedMetadata::Real(_)
+                )
+            })
+            .flat_map(|transaction| transaction.series.legs())
+            .cloned()
+            .collect::<Vec<TransactionData>>();
+        let interval =
+            snapshot_intervals(&account, &state.balance_snapshots, &legs)
+                .into_iter()
+                .find(|interval| interval.snapshot == snapshot)
+                .ok_or_else(|| Error::earliest_snapshot(&account, snapshot))?;
+        if interval.delta() != 0 {
+            return Err(Error::unbalanced(snapshot, interval.delta()));
+        }
+
+        let reconciliation = Reconciliation {
+            id: state.next_id(),
+            account,
+            snapshot,
+            start_date: interval.start_date,
+            end_date: interval.end_date,
+            adjustment: None,
+            reconciled_at: Utc::now(),
+        };
+        state.reconciliations.push(reconciliation.clone());
+        Ok(reconciliation)
+    }
+
+    async fn exchange_rates(&self) -> Result<Vec<ExchangeRate>> {
+        let mut rates = self.state().exchange_rates.clone();
+        rates.sort_by_key(|rate| rate.date);
+        Ok(rates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use budget_models::calculation::{Calculation, CalculationExt};
+    use budget_models::total::BurnUpTotal;
+    use chrono::TimeZone;
+
+    use super::*;
+
+    const ACCOUNT: &str = "Checking";
+
+    fn date(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, 10, day, 0, 0, 0).unwrap()
+    }
+
+    fn leg(summary: &str, amount: i64, day: u32) -> NewTransactionData {
+        NewTransactionData {
+            summary: summary.to_string(),
+            account: ACCOUNT.to_string(),
+            amount,
+            date: date(day),
+            fingerprint: Some(format!("{}-{}", summary, day)),
+            payee: None,
+        }
+    }
+
+    fn real(summary: &str, amount: i64, day: u32) -> NewTransaction {
+        NewTransaction {
+            metadata: TransactionTypedMetadata::Real(None),
+            series: NewTransactionSeries::Single(leg(summary, amount, day)),
+        }
+    }
+
+    fn categorized(
+        budget: i32,
+        line_item: &str,
+        amount: i64,
+        day: u32,
+    ) -> NewTransaction {
+        NewTransaction {
+            metadata: TransactionTypedMetadata::Real(Some(
+                TransactionMetadata {
+                    periodic_budget: Some(budget),
+                    one_time_budget: None,
+                    allocations: vec![Allocation {
+                        line_item: line_item.to_string(),
+                        amount,
+                    }],
+                },
+            )),
+            series: NewTransactionSeries::Single(leg(line_item, amount, day)),
+        }
+    }
+
+    fn batch() -> NewImportBatch {
+        NewImportBatch {
+            filename: "statement.csv".to_string(),
+            hash: "0123456789abcdef".to_string(),
+            transaction_type: TransactionType::Real,
+        }
+    }
+
+    fn snapshot(day: u32, amount: i64) -> NewBalanceSnapshot {
+        NewBalanceSnapshot {
+            account: ACCOUNT.to_string(),
+            date: date(day),
+            amount,
+        }
+    }
+
+    // A repository with one account, and a budget period for October.
+    async fn repository() -> Result<(MemoryRepository, i32)> {
+        let repository = MemoryRepository::new();
+        repository
+            .create_account(Account {
+                name: ACCOUNT.to_string(),
+                account_type: AccountType::Checking,
+                currency: Currency::Usd,
+                date_opened: Utc
+                    .with_ymd_and_hms(2022, 1, 1, 0, 0, 0)
+                    .unwrap(),
+                date_closed: None,
+            })
+            .await?;
+        for line_item in ["Groceries", "Rent"] {
+            repository
+                .create_line_item(LineItem {
+                    summary: line_item.to_string(),
+                    category: None,
+                })
+                .await?;
+        }
+        let budget = repository
+            .create_periodic_budget(NewPeriodicBudget {
+                start_date: date(1),
+                end_date: date(31),
+            })
+            .await?;
+        Ok((repository, budget.id))
+    }
+
+    #[tokio::test]
+    async fn import_links_legs_to_the_batch() -> Result<()> {
+        let (repository, _) = repository().await?;
+        repository
+            .create_transactions(vec![real("Paycheck", 150000, 14)])
+            .await?;
+        let transactions =
+            vec![real("Grocer", -4512, 3), real("Cafe", -650, 4)];
+        let (batch, snapshots) = repository
+            .import_transactions(batch(), transactions, vec![snapshot(5, 500)])
+            .await?;
+        assert_eq!(batch.row_count, 2);
+        assert_eq!(snapshots.len(), 1);
+
+        let (_, imported) = repository.import_batch(batch.id).await?;
+        let summaries = imported
+            .iter()
+            .map(|t| t.series.legs()[0].summary.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(summaries, vec!["Grocer", "Cafe"]);
+
+        assert_eq!(repository.undo_import(batch.id).await?, 2);
+        let remaining = repository
+            .transactions(&TransactionFilter::default())
+            .await?;
+        assert_eq!(remaining.len(), 1);
+        assert!(repository.balance_snapshots().await?.is_empty());
+        assert!(matches!(
+            repository.import_batch(batch.id).await,
+            Err(Error::NotFound { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reimported_transactions_are_duplicates() -> Result<()> {
+        let (repository, _) = repository().await?;
+        let transactions =
+            vec![real("Grocer", -4512, 3), real("Cafe", -650, 4)];
+        repository
+            .import_transactions(batch(), transactions.clone(), Vec::new())
+            .await?;
+
+        let mut again = transactions;
+        again.push(real("Hardware", -2399, 5));
+        let matches = repository.match_imports(&batch(), &again).await?;
+        assert!(matches!(matches[0], ImportMatch::Duplicate { .. }));
+        assert!(matches!(matches[1], ImportMatch::Duplicate { .. }));
+        assert_eq!(matches[2], ImportMatch::New);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn planned_transactions_are_not_duplicates() -> Result<()> {
+        let (repository, budget) = repository().await?;
+        let planned = NewTransaction {
+            metadata: TransactionTypedMetadata::Planned(
+                TransactionMetadata::single(budget, "Groceries", -4512),
+            ),
+            series: NewTransactionSeries::Single(leg("Grocer", -4512, 3)),
+        };
+        repository.create_transactions(vec![planned]).await?;
+
+        // The real transaction fulfils the plan, so it's imported.
+        let transactions = vec![real("Grocer", -4512, 3)];
+        let matches =
+            repository.match_imports(&batch(), &transactions).await?;
+        assert_eq!(matches, vec![ImportMatch::New]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn snapshots_verify_against_real_transactions() -> Result<()> {
+        let (repository, budget) = repository().await?;
+        repository
+            .create_balance_snapshot(snapshot(1, 100000))
+            .await?;
+        let end = repository
+            .create_balance_snapshot(snapshot(31, 90000))
+            .await?;
+        repository
+            .create_transactions(vec![
+                real("Grocer", -4000, 3),
+                categorized(budget, "Rent", -5000, 5),
+            ])
+            .await?;
+
+        // Planned transactions don't count towards the balance.
+        repository
+            .create_transactions(vec![NewTransaction {
+                metadata: TransactionTypedMetadata::Planned(
+                    TransactionMetadata {
+                        periodic_budget: Some(budget),
+                        one_time_budget: None,
+                        allocations: vec![Allocation {
+                            line_item: "Groceries".to_string(),
+                            amount: -3000,
+                        }],
+                    },
+                ),
+                series: NewTransactionSeries::Single(leg("Plan", -3000, 20)),
+            }])
+            .await?;
+
+        let snapshots = repository.balance_snapshots().await?;
+        let filter = TransactionFilter {
+            transaction_type: Some(TransactionType::Real),
+            ..Default::default()
+        };
+        let legs = repository
+            .transactions(&filter)
+            .await?
+            .iter()
+            .flat_map(|t| t.series.legs())
+            .cloned()
+            .collect::<Vec<TransactionData>>();
+        let intervals = snapshot_intervals(ACCOUNT, &snapshots, &legs);
+        assert_eq!(intervals.len(), 1);
+        assert_eq!(intervals[0].calculated, 91000);
+        assert_eq!(intervals[0].delta(), -1000);
+
+        assert!(matches!(
+            repository.reconcile(end.id).await,
+            Err(Error::Conflict(_))
+        ));
+        repository
+            .create_transactions(vec![real("Fee", -1000, 30)])
+            .await?;
+        let reconciliation = repository.reconcile(end.id).await?;
+        assert_eq!(reconciliation.start_date, date(1));
+        assert_eq!(reconciliation.end_date, date(31));
+        assert_eq!(repository.reconciliations().await?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reconciled_transactions_are_locked() -> Result<()> {
+        let (repository, _) = repository().await?;
+        repository
+            .create_balance_snapshot(snapshot(1, 100000))
+            .await?;
+        let end = repository
+            .create_balance_snapshot(snapshot(10, 95000))
+            .await?;
+        let (batch, _) = repository
+            .import_transactions(
+                batch(),
+                vec![real("Grocer", -5000, 3)],
+                Vec::new(),
+            )
+            .await?;
+        let (_, imported) = repository.import_batch(batch.id).await?;
+        let id = imported[0].id;
+        repository.reconcile(end.id).await?;
+
+        let conflict = |result: Result<()>| match result {
+            Err(e) => matches!(e.root(), Error::Conflict(_)),
+            Ok(_) => false,
+        };
+        assert!(conflict(
+            repository
+                .update_transaction(id, real("Grocer", -5500, 3))
+                .await
+                .map(|_| ())
+        ));
+        assert!(conflict(repository.delete_transaction(id).await));
+        assert!(conflict(
+            repository
+                .create_transactions(vec![real("Cafe", -650, 4)])
+                .await
+                .map(|_| ())
+        ));
+        assert!(conflict(repository.undo_import(batch.id).await.map(|_| ())));
+
+        // The interval includes its start, but not its end, which belongs to
+        // the next interval.
+        assert!(conflict(
+            repository
+                .create_transactions(vec![real("Deposit", 2000, 1)])
+                .await
+                .map(|_| ())
+        ));
+        let boundary = repository
+            .create_transactions(vec![real("Deposit", 2000, 10)])
+            .await?;
+        repository.delete_transaction(boundary[0].id).await?;
+
+        // Outside of the reconciled interval, nothing is locked.
+        let later = repository
+            .create_transactions(vec![real("Cafe", -650, 12)])
+            .await?;
+        repository.delete_transaction(later[0].id).await?;
+        assert_eq!(repository.import_batch(batch.id).await?.1.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn report_spending_by_line_item() -> Result<()> {
+        let (repository, budget) = repository().await?;
+        repository
+            .create_transactions(vec![
+                categorized(budget, "Groceries", -4512, 3),
+                categorized(budget, "Rent", -120000, 5),
+                categorized(budget, "Groceries", -3888, 17),
+                real("Uncategorized", -100, 18),
+            ])
+            .await?;
+
+        let mut spending = BurnUpTotal::default()
+            .map_input(|allocation: &Allocation| {
+                Money::new(allocation.amount, Currency::Usd)
+            })
+            .group_by(|allocation| allocation.line_item.clone());
+        let transactions = repository
+            .transactions(&TransactionFilter::default())
+            .await?;
+        for transaction in &transactions {
+            if let Some(metadata) = transaction.metadata.metadata() {
+                spending.apply_all(&metadata.allocations);
+            }
+        }
+
+        let spending = spending.calculate();
+        assert_eq!(spending.len(), 2);
+        let usd = |amount: i64| Some(Money::new(amount, Currency::Usd));
+        assert_eq!(spending["Groceries"], usd(-8400));
+        assert_eq!(spending["Rent"], usd(-120000));
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
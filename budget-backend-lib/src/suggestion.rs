@@ -0,0 +1,133 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            suggestion.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Line item suggestions for uncategorized real transactions,
+//                  learned from the transactions already categorized.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_models::categorizer::Categorizer;
+use budget_models::*;
+
+use crate::error::{Error, Result};
+use crate::repository::Repository;
+
+// Train a categorizer on every categorized real transaction, returning it
+// along with the real transactions.
+async fn train<R: Repository>(
+    repository: &R,
+) -> Result<(Categorizer, Vec<Transaction>)> {
+    let filter = TransactionFilter {
+        transaction_type: Some(TransactionType::Real),
+        ..Default::default()
+    };
+    let history = repository.transactions(&filter).await?;
+    Ok((Categorizer::train(&history), history))
+}
+
+// Suggest line items for a transaction, given the id of any of its legs, most
+// confident first.
+pub async fn suggest<R: Repository>(
+    repository: &R,
+    id: i32,
+) -> Result<Vec<LineItemSuggestion>> {
+    let transaction = repository.transaction(id).await?;
+    let (categorizer, _) = train(repository).await?;
+    Ok(categorizer.suggest(&transaction))
+}
+
+// Every uncategorized real transaction, in order of date, with its
+// suggestions.
+pub async fn suggest_all<R: Repository>(
+    repository: &R,
+) -> Result<Vec<(Transaction, Vec<LineItemSuggestion>)>> {
+    let (categorizer, history) = train(repository).await?;
+    Ok(history
+        .into_iter()
+        .filter(|transaction| {
+            matches!(
+                transaction.metadata,
+                TransactionTypedMetadata::Real(None)
+            )
+        })
+        .map(|transaction| {
+            let suggestions = categorizer.suggest(&transaction);
+            (transaction, suggestions)
+        })
+        .collect())
+}
+
+// Allocate a real transaction entirely to the line item, e.g. to accept a
+// suggestion. An uncategorized transaction is placed in the periodic budget
+// which includes its date.
+pub async fn accept<R: Repository>(
+    repository: &R,
+    id: i32,
+    line_item: &str,
+) -> Result<Transaction> {
+    let transaction = repository.transaction(id).await?;
+    let metadata = match transaction.metadata {
+        TransactionTypedMetadata::Real(Some(metadata)) => metadata,
+        TransactionTypedMetadata::Real(None) => {
+            let date = transaction.series.legs()[0].date;
+            let budget = repository
+                .periodic_budgets()
+                .await?
+                .into_iter()
+                .find(|b| b.start_date <= date && b.end_date >= date)
+                .ok_or_else(|| {
+                    Error::not_found(
+                        "periodic budget including",
+                        date.format("%d %b %Y"),
+                    )
+                })?;
+            TransactionMetadata {
+                periodic_budget: Some(budget.id),
+                one_time_budget: None,
+                allocations: Vec::new(),
+            }
+        }
+        TransactionTypedMetadata::Planned(_) => {
+            return Err(Error::Conflict(format!(
+                "transaction {} is planned, and only real transactions can \
+                 be categorized",
+                id
+            )))
+        }
+    };
+
+    let amount = transaction.series.amount();
+    let metadata = TransactionMetadata {
+        allocations: vec![Allocation {
+            line_item: line_item.to_string(),
+            amount,
+        }],
+        ..metadata
+    };
+    let new_transaction = NewTransaction {
+        metadata: TransactionTypedMetadata::Real(Some(metadata)),
+        series: transaction.series.into(),
+    };
+    repository.update_transaction(id, new_transaction).await
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,177 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            tag.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Operations on tags, which label transactions across line
+//                  items and budgets.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_models::*;
+use sea_orm::prelude::*;
+use sea_orm::{QueryOrder, Set};
+
+use crate::error::Result;
+use crate::prelude::*;
+
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    tag: &str,
+) -> Result<Tag> {
+    let model = tags::ActiveModel {
+        tag: Set(tag.to_string()),
+        ..Default::default()
+    };
+    Ok(Tags::insert(model).exec_with_returning(db).await?.into())
+}
+
+pub async fn list(db: &sea_orm::DatabaseConnection) -> Result<Vec<Tag>> {
+    Ok(Tags::find()
+        .order_by_asc(tags::Column::Tag)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|tag| tag.into())
+        .collect())
+}
+
+// Look up a tag by name, creating it if it doesn't exist yet.
+async fn find_or_create(
+    db: &sea_orm::DatabaseConnection,
+    tag: &str,
+) -> Result<Tag> {
+    match Tags::find()
+        .filter(tags::Column::Tag.eq(tag))
+        .one(db)
+        .await?
+    {
+        Some(model) => Ok(model.into()),
+        None => create(db, tag).await,
+    }
+}
+
+// Attach a tag to a transaction. Attaching a tag twice has no effect.
+pub async fn attach(
+    db: &sea_orm::DatabaseConnection,
+    transaction: i32,
+    tag: &str,
+) -> Result<Tag> {
+    let tag = find_or_create(db, tag).await?;
+    let existing = TransactionTags::find_by_id((transaction, tag.id))
+        .one(db)
+        .await?;
+    if existing.is_none() {
+        let model = transaction_tags::ActiveModel {
+            transaction: Set(transaction),
+            tag: Set(tag.id),
+        };
+        TransactionTags::insert(model).exec(db).await?;
+    }
+    Ok(tag)
+}
+
+// Remove a tag from a transaction. The tag itself is not deleted.
+pub async fn detach(
+    db: &sea_orm::DatabaseConnection,
+    transaction: i32,
+    tag: &str,
+) -> Result<()> {
+    let tag = Tags::find()
+        .filter(tags::Column::Tag.eq(tag))
+        .one(db)
+        .await?;
+    if let Some(tag) = tag {
+        TransactionTags::delete_by_id((transaction, tag.id))
+            .exec(db)
+            .await?;
+    }
+    Ok(())
+}
+
+// The tags attached to a transaction.
+pub async fn tags_of(
+    db: &sea_orm::DatabaseConnection,
+    transaction: i32,
+) -> Result<Vec<Tag>> {
+    Ok(Tags::find()
+        .inner_join(TransactionTags)
+        .filter(transaction_tags::Column::Transaction.eq(transaction))
+        .order_by_asc(tags::Column::Tag)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|tag| tag.into())
+        .collect())
+}
+
+// The ids of the transactions selected by the filter.
+pub async fn filter(
+    db: &sea_orm::DatabaseConnection,
+    filter: &TagFilter,
+) -> Result<Vec<i32>> {
+    let tags: HashMap<i32, String> = Tags::find()
+        .filter(tags::Column::Tag.is_in(filter.tags().to_vec()))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|tag| (tag.id, tag.tag))
+        .collect();
+
+    let mut transactions: HashMap<i32, Vec<&str>> = HashMap::new();
+    let rows = TransactionTags::find()
+        .filter(transaction_tags::Column::Tag.is_in(tags.keys().copied()))
+        .all(db)
+        .await?;
+    for row in &rows {
+        transactions
+            .entry(row.transaction)
+            .or_default()
+            .push(&tags[&row.tag]);
+    }
+
+    let mut ids = transactions
+        .into_iter()
+        .filter(|(_, tags)| filter.matches(tags))
+        .map(|(id, _)| id)
+        .collect::<Vec<i32>>();
+    ids.sort_unstable();
+    Ok(ids)
+}
+
+// The transactions selected by the filter, in order of date.
+pub async fn find_transactions(
+    db: &sea_orm::DatabaseConnection,
+    filter: &TagFilter,
+) -> Result<Vec<TransactionData>> {
+    let ids = self::filter(db, filter).await?;
+    Ok(Transactions::find()
+        .filter(transactions::Column::Id.is_in(ids))
+        .order_by_asc(transactions::Column::Date)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|transaction| transaction.into())
+        .collect())
+}
+
+///////////////////////////////////////////////////////////////////////////////
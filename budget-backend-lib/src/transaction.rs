@@ -8,7 +8,7 @@
 //
 // CREATED:         11/17/2022
 //
-// LAST EDITED:     11/19/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,90 +25,633 @@
 // limitations under the License.
 ////
 
+use std::collections::{HashMap, HashSet};
+
 use budget_models::*;
+use chrono::{DateTime, Utc};
 use sea_orm::prelude::*;
-use sea_orm::Set;
+use sea_orm::{ConnectionTrait, NotSet, QueryOrder, Set, TransactionTrait};
 
+use crate::error::{Error, Result};
 use crate::prelude::*;
+use crate::{reconciliation, tag, validation};
 
-async fn create_transaction(
-    db: &sea_orm::DatabaseConnection,
-    data: transactions::ActiveModel,
-    metadata: TransactionTypedMetadata,
-) -> Result<(transactions::Model, i32), sea_orm::DbErr> {
-    // Create the transactions instance
-    let response = Transactions::insert(data).exec_with_returning(db).await?;
-
-    // Create the planned/real transactions instance
+// Create an instance of real_transactions or planned_transactions for each of
+// the allocations of a transaction. The allocations are apportioned to the
+// amount of this leg of the series.
+async fn create_metadata<C: ConnectionTrait>(
+    db: &C,
+    leg: &transactions::Model,
+    metadata: &TransactionTypedMetadata,
+) -> Result<(), DbErr> {
     match metadata {
-        TransactionTypedMetadata::Real(real) => {
+        TransactionTypedMetadata::Real(None) => {
             let model = real_transactions::ActiveModel {
-                transaction: Set(response.id),
-                line_item: Set(real.as_ref().map(|t| t.line_item.clone())),
-                periodic_budget: Set(real.map(|t| t.periodic_budget)),
+                transaction: Set(leg.id),
+                line_item: Set(None),
+                periodic_budget: Set(None),
+                amount: Set(leg.amount),
                 ..Default::default()
             };
-            let id = RealTransactions::insert(model)
-                .exec(db)
-                .await?
-                .last_insert_id;
-            Ok((response, id))
+            RealTransactions::insert(model).exec(db).await?;
         }
 
-        TransactionTypedMetadata::Planned(transaction) => {
-            let model = planned_transactions::ActiveModel {
-                transaction: Set(response.id),
-                line_item: Set(transaction.line_item),
-                periodic_budget: Set(transaction.periodic_budget),
-                ..Default::default()
+        TransactionTypedMetadata::Real(Some(real)) => {
+            let models =
+                real.apportion(leg.amount).into_iter().map(|allocation| {
+                    real_transactions::ActiveModel {
+                        transaction: Set(leg.id),
+                        line_item: Set(Some(allocation.line_item)),
+                        periodic_budget: Set(real.periodic_budget),
+                        one_time_budget: Set(real.one_time_budget),
+                        amount: Set(allocation.amount),
+                        ..Default::default()
+                    }
+                });
+            RealTransactions::insert_many(models).exec(db).await?;
+        }
+
+        TransactionTypedMetadata::Planned(planned) => {
+            let models =
+                planned.apportion(leg.amount).into_iter().map(|allocation| {
+                    planned_transactions::ActiveModel {
+                        transaction: Set(leg.id),
+                        line_item: Set(allocation.line_item),
+                        periodic_budget: Set(planned.periodic_budget),
+                        one_time_budget: Set(planned.one_time_budget),
+                        amount: Set(allocation.amount),
+                        ..Default::default()
+                    }
+                });
+            PlannedTransactions::insert_many(models).exec(db).await?;
+        }
+    }
+
+    Ok(())
+}
+
+// Create the transactions instance, and its real or planned instances.
+async fn create_transaction<C: ConnectionTrait>(
+    db: &C,
+    data: transactions::ActiveModel,
+    metadata: &TransactionTypedMetadata,
+) -> Result<transactions::Model, DbErr> {
+    let response = Transactions::insert(data).exec_with_returning(db).await?;
+    create_metadata(db, &response, metadata).await?;
+    Ok(response)
+}
+
+// Sum the allocations of the given rows into metadata. Rows of different legs
+// that share a line item are combined, in the order they first appear.
+fn combine_allocations<I>(rows: I) -> TransactionMetadata
+where
+    I: Iterator<Item = (Option<i32>, Option<i32>, String, i64)>,
+{
+    let mut metadata = TransactionMetadata::default();
+    for (periodic_budget, one_time_budget, line_item, amount) in rows {
+        metadata.periodic_budget =
+            metadata.periodic_budget.or(periodic_budget);
+        metadata.one_time_budget =
+            metadata.one_time_budget.or(one_time_budget);
+        match metadata
+            .allocations
+            .iter_mut()
+            .find(|allocation| allocation.line_item == line_item)
+        {
+            Some(allocation) => allocation.amount += amount,
+            None => {
+                metadata.allocations.push(Allocation { line_item, amount })
+            }
+        }
+    }
+    metadata
+}
+
+// Reassemble the metadata of a transaction from the real or planned rows of
+// its legs. The allocations of a linked series were apportioned to each leg,
+// so they're recovered from the legs which received money, which together
+// make up the amount of the series.
+fn assemble_metadata(
+    legs: &[transactions::Model],
+    real: &HashMap<i32, Vec<real_transactions::Model>>,
+    planned: &HashMap<i32, Vec<planned_transactions::Model>>,
+) -> TransactionTypedMetadata {
+    let legs = legs
+        .iter()
+        .filter(|leg| legs.len() == 1 || leg.amount > 0)
+        .map(|leg| leg.id)
+        .collect::<Vec<i32>>();
+
+    let planned_rows = legs
+        .iter()
+        .filter_map(|leg| planned.get(leg))
+        .flatten()
+        .collect::<Vec<&planned_transactions::Model>>();
+    if !planned_rows.is_empty() {
+        return TransactionTypedMetadata::Planned(combine_allocations(
+            planned_rows.into_iter().map(|row| {
+                (
+                    row.periodic_budget,
+                    row.one_time_budget,
+                    row.line_item.clone(),
+                    row.amount,
+                )
+            }),
+        ));
+    }
+
+    let real_rows = legs
+        .iter()
+        .filter_map(|leg| real.get(leg))
+        .flatten()
+        .filter_map(|row| {
+            row.line_item.clone().map(|line_item| {
+                (
+                    row.periodic_budget,
+                    row.one_time_budget,
+                    line_item,
+                    row.amount,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    if real_rows.is_empty() {
+        TransactionTypedMetadata::Real(None)
+    } else {
+        TransactionTypedMetadata::Real(Some(combine_allocations(
+            real_rows.into_iter(),
+        )))
+    }
+}
+
+// Reassemble whole transactions from their legs. Every leg of a series must be
+// present. Transactions are returned in the order of their first leg.
+pub(crate) async fn assemble<C: ConnectionTrait>(
+    db: &C,
+    legs: Vec<transactions::Model>,
+) -> Result<Vec<Transaction>, DbErr> {
+    let ids = legs.iter().map(|leg| leg.id).collect::<Vec<i32>>();
+    let mut real: HashMap<i32, Vec<real_transactions::Model>> = HashMap::new();
+    for row in RealTransactions::find()
+        .filter(real_transactions::Column::Transaction.is_in(ids.clone()))
+        .order_by_asc(real_transactions::Column::Id)
+        .all(db)
+        .await?
+    {
+        real.entry(row.transaction).or_default().push(row);
+    }
+
+    let mut planned: HashMap<i32, Vec<planned_transactions::Model>> =
+        HashMap::new();
+    for row in PlannedTransactions::find()
+        .filter(planned_transactions::Column::Transaction.is_in(ids))
+        .order_by_asc(planned_transactions::Column::Id)
+        .all(db)
+        .await?
+    {
+        planned.entry(row.transaction).or_default().push(row);
+    }
+
+    let mut groups: Vec<Vec<transactions::Model>> = Vec::new();
+    let mut series: HashMap<i32, usize> = HashMap::new();
+    for leg in legs {
+        match leg.series.and_then(|id| series.get(&id).copied()) {
+            Some(index) => groups[index].push(leg),
+            None => {
+                if let Some(id) = leg.series {
+                    series.insert(id, groups.len());
+                }
+                groups.push(vec![leg]);
+            }
+        }
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|mut legs| {
+            legs.sort_by_key(|leg| leg.id);
+            let metadata = assemble_metadata(&legs, &real, &planned);
+            let id = legs.last().map(|leg| leg.id).unwrap_or_default();
+            let series = match legs.first().and_then(|leg| leg.series) {
+                Some(_) => TransactionSeries::Linked(
+                    legs.into_iter().map(|leg| leg.into()).collect(),
+                ),
+                None => TransactionSeries::Single(legs.remove(0).into()),
             };
-            let id = PlannedTransactions::insert(model)
-                .exec(db)
-                .await?
-                .last_insert_id;
-            Ok((response, id))
+            Transaction {
+                id,
+                metadata,
+                series,
+            }
+        })
+        .collect())
+}
+
+// The account and date of each leg, for checking against reconciled periods.
+fn leg_dates(legs: &[transactions::Model]) -> Vec<(String, DateTime<Utc>)> {
+    legs.iter()
+        .map(|leg| (leg.account.clone(), leg.date.into()))
+        .collect()
+}
+
+// Every leg of the series that the given leg belongs to, in order of id.
+async fn series_legs<C: ConnectionTrait>(
+    db: &C,
+    leg: transactions::Model,
+) -> Result<Vec<transactions::Model>, DbErr> {
+    match leg.series {
+        Some(series) => {
+            Transactions::find()
+                .filter(transactions::Column::Series.eq(series))
+                .order_by_asc(transactions::Column::Id)
+                .all(db)
+                .await
         }
+        None => Ok(vec![leg]),
     }
 }
 
-pub async fn create(
-    db: &sea_orm::DatabaseConnection,
+async fn find_leg<C: ConnectionTrait>(
+    db: &C,
+    id: i32,
+) -> Result<transactions::Model> {
+    Transactions::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| Error::not_found("transaction", id))
+}
+
+// Insert every row of a transaction. Fails if any leg is in a reconciled
+// period. Callers are expected to run this inside of a database transaction,
+// so that a failure doesn't leave orphaned legs.
+pub(crate) async fn insert<C: ConnectionTrait>(
+    db: &C,
     transaction: NewTransaction,
-) -> Result<Transaction, sea_orm::DbErr> {
+) -> Result<Transaction> {
+    let dates = transaction
+        .series
+        .legs()
+        .into_iter()
+        .map(|leg| (leg.account.clone(), leg.date))
+        .collect::<Vec<(String, DateTime<Utc>)>>();
+    reconciliation::check_unlocked(db, &dates).await?;
+
     let NewTransaction { metadata, series } = transaction;
     match series {
         NewTransactionSeries::Single(single) => {
-            let (data, id) =
-                create_transaction(db, single.into(), metadata.clone())
-                    .await?;
+            let data =
+                create_transaction(db, single.into(), &metadata).await?;
             Ok(Transaction {
-                id,
+                id: data.id,
                 series: TransactionSeries::Single(data.into()),
                 metadata,
             })
         }
 
-        NewTransactionSeries::Transfer {
-            starting,
-            completing,
-        } => {
-            let (start_data, _) =
-                create_transaction(db, starting.into(), metadata.clone())
-                    .await?;
-            let mut completed: transactions::ActiveModel = completing.into();
-            completed.completed_by = Set(Some(start_data.id));
-            let (complete_data, id) =
-                create_transaction(db, completed, metadata.clone()).await?;
+        NewTransactionSeries::Linked(legs) => {
+            let group = transaction_series::ActiveModel::default();
+            let group = transaction_series::Entity::insert(group)
+                .exec_with_returning(db)
+                .await?;
+
+            let mut created = Vec::new();
+            for leg in legs {
+                let mut model: transactions::ActiveModel = leg.into();
+                model.series = Set(Some(group.id));
+                created.push(create_transaction(db, model, &metadata).await?);
+            }
+
             Ok(Transaction {
-                id,
-                series: TransactionSeries::Transfer {
-                    starting: start_data.into(),
-                    completing: complete_data.into(),
-                },
+                id: created.last().map(|leg| leg.id).unwrap_or_default(),
+                series: TransactionSeries::Linked(
+                    created.into_iter().map(|leg| leg.into()).collect(),
+                ),
                 metadata,
             })
         }
     }
 }
 
+// Validate every transaction, failing with Error::FailedRecord for the first
+// one which is invalid.
+pub(crate) async fn validate_many(
+    db: &sea_orm::DatabaseConnection,
+    transactions: &[NewTransaction],
+) -> Result<()> {
+    let context = validation::load_context(db).await?;
+    for (index, transaction) in transactions.iter().enumerate() {
+        context
+            .validate(transaction)
+            .map_err(|e| Error::failed_record(index, e.into()))?;
+    }
+    Ok(())
+}
+
+// Insert many transactions, failing with Error::FailedRecord for the first
+// one which can't be inserted. Like insert, this is expected to be run
+// inside of a database transaction.
+pub(crate) async fn insert_many<C: ConnectionTrait>(
+    db: &C,
+    transactions: Vec<NewTransaction>,
+) -> Result<Vec<Transaction>> {
+    let mut created = Vec::new();
+    for (index, transaction) in transactions.into_iter().enumerate() {
+        let transaction = insert(db, transaction)
+            .await
+            .map_err(|e| Error::failed_record(index, e))?;
+        created.push(transaction);
+    }
+    Ok(created)
+}
+
+// Remove the legs, along with every other leg of their series and the series
+// themselves. Fails if any leg is in a reconciled period. Returns the number
+// of transactions removed. Like insert, this is expected to be run inside of
+// a database transaction.
+pub(crate) async fn remove<C: ConnectionTrait>(
+    db: &C,
+    mut legs: Vec<transactions::Model>,
+) -> Result<usize> {
+    let series = legs
+        .iter()
+        .filter_map(|leg| leg.series)
+        .collect::<HashSet<i32>>();
+    if !series.is_empty() {
+        let found = legs.iter().map(|leg| leg.id).collect::<HashSet<i32>>();
+        let others = Transactions::find()
+            .filter(transactions::Column::Series.is_in(series.clone()))
+            .all(db)
+            .await?;
+        legs.extend(others.into_iter().filter(|leg| !found.contains(&leg.id)));
+    }
+    reconciliation::check_unlocked(db, &leg_dates(&legs)).await?;
+
+    let count =
+        series.len() + legs.iter().filter(|leg| leg.series.is_none()).count();
+    Transactions::delete_many()
+        .filter(transactions::Column::Id.is_in(legs.iter().map(|leg| leg.id)))
+        .exec(db)
+        .await?;
+    if !series.is_empty() {
+        transaction_series::Entity::delete_many()
+            .filter(transaction_series::Column::Id.is_in(series))
+            .exec(db)
+            .await?;
+    }
+    Ok(count)
+}
+
+// Create a transaction. The transaction is validated against the database
+// first, and a ValidationError listing every violation is returned if it's
+// invalid. Either every row of the transaction is created, or none are. The
+// id of the returned transaction is the id of its final leg. Transactions
+// can't be created in reconciled periods.
+pub async fn create(
+    db: &sea_orm::DatabaseConnection,
+    transaction: NewTransaction,
+) -> Result<Transaction> {
+    validation::validate(db, &transaction).await?;
+    let txn = db.begin().await?;
+    let created = insert(&txn, transaction).await?;
+    txn.commit().await?;
+    Ok(created)
+}
+
+// Create many transactions at once. Either all of the transactions are
+// created, or none are. If any transaction is invalid or can't be inserted,
+// e.g. because it's in a reconciled period, the error is
+// Error::FailedRecord, with the index of that transaction.
+pub async fn create_many(
+    db: &sea_orm::DatabaseConnection,
+    transactions: Vec<NewTransaction>,
+) -> Result<Vec<Transaction>> {
+    validate_many(db, &transactions).await?;
+
+    // Dropping txn without committing rolls back.
+    let txn = db.begin().await?;
+    let created = insert_many(&txn, transactions).await?;
+    txn.commit().await?;
+    Ok(created)
+}
+
+// Get a transaction by the id of any of its legs.
+pub async fn get(
+    db: &sea_orm::DatabaseConnection,
+    id: i32,
+) -> Result<Transaction> {
+    let leg = find_leg(db, id).await?;
+    let legs = series_legs(db, leg).await?;
+    assemble(db, legs)
+        .await?
+        .pop()
+        .ok_or_else(|| Error::not_found("transaction", id))
+}
+
+// List the transactions selected by the filter, in order of date.
+pub async fn list(
+    db: &sea_orm::DatabaseConnection,
+    filter: &TransactionFilter,
+) -> Result<Vec<Transaction>> {
+    let mut query = Transactions::find();
+    if let Some(account) = &filter.account {
+        query =
+            query.filter(transactions::Column::Account.eq(account.clone()));
+    }
+    if let Some(payee) = filter.payee {
+        query = query.filter(transactions::Column::Payee.eq(payee));
+    }
+    if let Some(start_date) = filter.start_date {
+        query = query.filter(transactions::Column::Date.gte(start_date));
+    }
+    if let Some(end_date) = filter.end_date {
+        query = query.filter(transactions::Column::Date.lte(end_date));
+    }
+    if let Some(tags) = filter.tags.as_ref().filter(|t| !t.selects_all()) {
+        let ids = tag::filter(db, tags).await?;
+        query = query.filter(transactions::Column::Id.is_in(ids));
+    }
+
+    // Pull in the rest of the legs of any series that matched.
+    let mut legs = query.all(db).await?;
+    let series = legs
+        .iter()
+        .filter_map(|leg| leg.series)
+        .collect::<HashSet<i32>>();
+    if !series.is_empty() {
+        let found = legs.iter().map(|leg| leg.id).collect::<HashSet<i32>>();
+        let others = Transactions::find()
+            .filter(transactions::Column::Series.is_in(series))
+            .all(db)
+            .await?;
+        legs.extend(others.into_iter().filter(|leg| !found.contains(&leg.id)));
+    }
+    legs.sort_by_key(|leg| (leg.date, leg.id));
+
+    Ok(assemble(db, legs)
+        .await?
+        .into_iter()
+        .filter(|transaction| filter.matches(transaction))
+        .collect())
+}
+
+// Replace a transaction with new data, given the id of any of its legs. The
+// legs of the transaction are updated in place, so tags and other
+// associations of the legs are kept. Legs are added or removed as necessary.
+// The id of the returned transaction is the id of its final leg. Transactions
+// in reconciled periods can't be updated.
+pub async fn update(
+    db: &sea_orm::DatabaseConnection,
+    id: i32,
+    transaction: NewTransaction,
+) -> Result<Transaction> {
+    validation::validate(db, &transaction).await?;
+    let txn = db.begin().await?;
+    let leg = find_leg(&txn, id).await?;
+    let existing = series_legs(&txn, leg).await?;
+
+    // Neither the old nor the new legs may fall in a reconciled period.
+    let mut dates = leg_dates(&existing);
+    dates.extend(
+        transaction
+            .series
+            .legs()
+            .into_iter()
+            .map(|leg| (leg.account.clone(), leg.date)),
+    );
+    reconciliation::check_unlocked(&txn, &dates).await?;
+    let existing_ids = existing.iter().map(|leg| leg.id).collect::<Vec<i32>>();
+    let old_series = existing.first().and_then(|leg| leg.series);
+
+    let NewTransaction { metadata, series } = transaction;
+    let new_series = match &series {
+        NewTransactionSeries::Single(_) => None,
+        NewTransactionSeries::Linked(_) => match old_series {
+            Some(id) => Some(id),
+            None => {
+                let group = transaction_series::ActiveModel::default();
+                let group = transaction_series::Entity::insert(group)
+                    .exec_with_returning(&txn)
+                    .await?;
+                Some(group.id)
+            }
+        },
+    };
+
+    RealTransactions::delete_many()
+        .filter(
+            real_transactions::Column::Transaction.is_in(existing_ids.clone()),
+        )
+        .exec(&txn)
+        .await?;
+    PlannedTransactions::delete_many()
+        .filter(
+            planned_transactions::Column::Transaction
+                .is_in(existing_ids.clone()),
+        )
+        .exec(&txn)
+        .await?;
+
+    let mut existing_ids = existing_ids.into_iter();
+    let mut updated = Vec::new();
+    for data in series.legs() {
+        let mut model: transactions::ActiveModel = data.clone().into();
+        model.series = Set(new_series);
+        let leg = match existing_ids.next() {
+            Some(id) => {
+                // Keep the fingerprint and payee of an imported leg, unless
+                // the new data has its own.
+                if data.fingerprint.is_none() {
+                    model.fingerprint = NotSet;
+                }
+                if data.payee.is_none() {
+                    model.payee = NotSet;
+                }
+                model.id = Set(id);
+                Transactions::update(model).exec(&txn).await?
+            }
+            None => {
+                Transactions::insert(model)
+                    .exec_with_returning(&txn)
+                    .await?
+            }
+        };
+        create_metadata(&txn, &leg, &metadata).await?;
+        updated.push(leg);
+    }
+
+    // Remove the legs that are no longer part of the transaction, and the
+    // series, if the transaction is no longer linked.
+    let removed = existing_ids.collect::<Vec<i32>>();
+    if !removed.is_empty() {
+        Transactions::delete_many()
+            .filter(transactions::Column::Id.is_in(removed))
+            .exec(&txn)
+            .await?;
+    }
+    if let (Some(id), None) = (old_series, new_series) {
+        transaction_series::Entity::delete_by_id(id)
+            .exec(&txn)
+            .await?;
+    }
+    txn.commit().await?;
+
+    Ok(Transaction {
+        id: updated.last().map(|leg| leg.id).unwrap_or_default(),
+        series: match series {
+            NewTransactionSeries::Single(_) => {
+                TransactionSeries::Single(updated.remove(0).into())
+            }
+            NewTransactionSeries::Linked(_) => TransactionSeries::Linked(
+                updated.into_iter().map(|leg| leg.into()).collect(),
+            ),
+        },
+        metadata,
+    })
+}
+
+// Delete a transaction, given the id of any of its legs. Every leg of the
+// series is deleted, along with its real or planned instances. Transactions
+// in reconciled periods can't be deleted.
+pub async fn delete(db: &sea_orm::DatabaseConnection, id: i32) -> Result<()> {
+    let txn = db.begin().await?;
+    let leg = find_leg(&txn, id).await?;
+    remove(&txn, vec![leg]).await?;
+    txn.commit().await?;
+    Ok(())
+}
+
+// Delete every transaction of the given type which refers to the periodic
+// budget, along with every leg of their series. Returns the number of
+// transactions deleted.
+pub async fn delete_all(
+    db: &sea_orm::DatabaseConnection,
+    transaction_type: &TransactionType,
+    budget: i32,
+) -> Result<usize> {
+    let txn = db.begin().await?;
+    let ids = match transaction_type {
+        TransactionType::Real => RealTransactions::find()
+            .filter(real_transactions::Column::PeriodicBudget.eq(budget))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|real| real.transaction)
+            .collect::<HashSet<i32>>(),
+        TransactionType::Planned => PlannedTransactions::find()
+            .filter(planned_transactions::Column::PeriodicBudget.eq(budget))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|planned| planned.transaction)
+            .collect::<HashSet<i32>>(),
+    };
+    let legs = Transactions::find()
+        .filter(transactions::Column::Id.is_in(ids))
+        .all(&txn)
+        .await?;
+    let count = remove(&txn, legs).await?;
+    txn.commit().await?;
+    Ok(count)
+}
+
 ///////////////////////////////////////////////////////////////////////////////
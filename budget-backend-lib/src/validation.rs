@@ -0,0 +1,79 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            validation.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Validation of transactions against the database.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_models::*;
+use sea_orm::prelude::*;
+
+use crate::error::Result;
+use crate::prelude::*;
+
+// Load everything a transaction may refer to. When validating many
+// transactions, load the context once and reuse it.
+pub async fn load_context(
+    db: &sea_orm::DatabaseConnection,
+) -> Result<ValidationContext, DbErr> {
+    let accounts = Accounts::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|account| account.into())
+        .collect();
+    let line_items = LineItems::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|line_item| line_item.summary)
+        .collect();
+    let periodic_budgets = PeriodicBudgets::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|budget| budget.into())
+        .collect();
+    let one_time_budgets = OneTimeBudgets::find()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|budget| budget.into())
+        .collect();
+    Ok(ValidationContext::new(
+        accounts,
+        line_items,
+        periodic_budgets,
+        one_time_budgets,
+    ))
+}
+
+// Validate a transaction against the current contents of the database.
+pub async fn validate(
+    db: &sea_orm::DatabaseConnection,
+    transaction: &NewTransaction,
+) -> Result<()> {
+    load_context(db).await?.validate(transaction)?;
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,7 @@
 //
 // CREATED:         11/27/2022
 //
-// LAST EDITED:     12/02/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,42 +25,74 @@
 // limitations under the License.
 ////
 
-use budget_backend_lib::prelude::*;
+use std::collections::HashMap;
+
+use budget_backend_lib::currency::CurrencyConverter;
+use budget_backend_lib::reconciliation;
+use budget_backend_lib::repository::{DatabaseRepository, Repository};
+use budget_models::{display, models, Currency, Money};
+use chrono::{DateTime, Utc};
 use clap::Subcommand;
-use sea_orm::prelude::*;
 use sea_orm::DatabaseConnection;
-use sea_orm::QueryOrder;
+use table_iter::prelude::*;
+
+use crate::table;
+
+// Format an amount in the account currency, along with its value in the base
+// currency, if one was requested.
+fn display_amount(
+    money: Money,
+    date: &DateTime<Utc>,
+    converter: Option<(&CurrencyConverter, Currency)>,
+) -> anyhow::Result<String> {
+    match converter {
+        Some((converter, currency)) if currency != money.currency() => {
+            let converted = converter.convert(money, currency, *date)?;
+            Ok(format!("{} ({})", money, converted))
+        }
+        _ => Ok(money.to_string()),
+    }
+}
 
-async fn verify_account_snapshots<'a, I>(
-    account: accounts::Model,
+fn verify_account_snapshots<'a, I>(
+    account: &models::Account,
     mut account_snapshots: I,
-    transactions: Vec<&transactions::Model>,
+    transactions: Vec<&models::TransactionData>,
+    converter: Option<(&CurrencyConverter, Currency)>,
 ) -> anyhow::Result<()>
 where
-    I: Iterator<Item = &'a balance_snapshots::Model>,
+    I: Iterator<Item = &'a models::BalanceSnapshot>,
 {
     let previous = account_snapshots.next();
-    if None == previous {
+    if previous.is_none() {
         return Ok(());
     }
     let mut previous = previous.unwrap();
 
+    let money = |amount: i64| Money::new(amount, account.currency);
     for snapshot in account_snapshots {
-        let calculated = previous.amount
+        let calculated = money(previous.amount)
             + transactions
                 .iter()
                 .filter_map(|t| {
                     if t.date >= previous.date && t.date < snapshot.date {
-                        Some(t.amount)
+                        Some(money(t.amount))
                     } else {
                         None
                     }
                 })
-                .sum::<i64>();
-        if calculated != snapshot.amount {
+                .sum::<Money>();
+        if calculated != money(snapshot.amount) {
             println!(
                 "{}, {}: Calculated amount: {}, Snapshot amount: {}",
-                account.name, snapshot.date, calculated, snapshot.amount
+                account.name,
+                snapshot.date,
+                display_amount(calculated, &snapshot.date, converter)?,
+                display_amount(
+                    money(snapshot.amount),
+                    &snapshot.date,
+                    converter
+                )?,
             );
         }
         previous = snapshot;
@@ -69,40 +101,194 @@ where
     Ok(())
 }
 
-async fn verify(db: &DatabaseConnection) -> anyhow::Result<()> {
+async fn verify<R: Repository>(
+    repository: &R,
+    currency: Option<Currency>,
+) -> anyhow::Result<()> {
     // Trying to get away with as few transactions as possible.
-    let accounts = Accounts::find().all(db).await?;
-    let balance_snapshots = BalanceSnapshots::find()
-        .order_by_asc(<BalanceSnapshots as EntityTrait>::Column::Date)
-        .all(db)
-        .await?;
-    let transactions = Transactions::find()
-        .order_by_asc(<BalanceSnapshots as EntityTrait>::Column::Date)
-        .all(db)
+    let accounts = repository.accounts().await?;
+    let balance_snapshots = repository.balance_snapshots().await?;
+    let transactions = repository
+        .transactions(&models::TransactionFilter::default())
         .await?;
+    let legs = transactions
+        .iter()
+        .flat_map(|transaction| transaction.series.legs())
+        .collect::<Vec<&models::TransactionData>>();
+    let converter = match currency {
+        Some(_) => {
+            Some(CurrencyConverter::new(repository.exchange_rates().await?))
+        }
+        None => None,
+    };
     for account in accounts {
         print!("{}...", &account.name);
-        let name = account.name.clone();
         let account_snapshots = balance_snapshots
             .iter()
-            .filter(|snapshot| snapshot.account == name);
-        let name = account.name.clone();
-        let account_transactions = transactions
+            .filter(|snapshot| snapshot.account == account.name);
+        let account_transactions = legs
             .iter()
-            .filter(|transaction| transaction.account == name)
-            .collect::<Vec<&transactions::Model>>();
+            .filter(|leg| leg.account == account.name)
+            .copied()
+            .collect::<Vec<&models::TransactionData>>();
         verify_account_snapshots(
-            account,
+            &account,
             account_snapshots,
             account_transactions,
-        )
-        .await?;
+            converter.as_ref().zip(currency),
+        )?;
         println!("OK");
     }
 
     Ok(())
 }
 
+#[derive(Fields, FieldNames)]
+struct DiscrepancyRecord {
+    #[fields(rename = "Snapshot")]
+    snapshot: i32,
+    #[fields(rename = "Account")]
+    account: String,
+    #[fields(rename = "Start Date")]
+    start_date: String,
+    #[fields(rename = "End Date")]
+    end_date: String,
+    #[fields(rename = "Calculated")]
+    calculated: Money,
+    #[fields(rename = "Snapshot Amount")]
+    expected: Money,
+    #[fields(rename = "Delta")]
+    delta: Money,
+    #[fields(rename = "Likely Causes")]
+    causes: String,
+}
+
+#[derive(Fields, FieldNames)]
+struct ReconciliationRecord {
+    #[fields(rename = "Snapshot")]
+    snapshot: i32,
+    #[fields(rename = "Account")]
+    account: String,
+    #[fields(rename = "Start Date")]
+    start_date: String,
+    #[fields(rename = "End Date")]
+    end_date: String,
+    #[fields(rename = "Adjustment", with = "table_iter::display::option")]
+    adjustment: Option<i32>,
+    #[fields(rename = "Reconciled")]
+    reconciled_at: String,
+}
+
+impl From<models::Reconciliation> for ReconciliationRecord {
+    fn from(value: models::Reconciliation) -> Self {
+        ReconciliationRecord {
+            snapshot: value.snapshot,
+            account: value.account,
+            start_date: display::date(&value.start_date.into()),
+            end_date: display::date(&value.end_date.into()),
+            adjustment: value.adjustment,
+            reconciled_at: display::date(&value.reconciled_at.into()),
+        }
+    }
+}
+
+fn describe_cause(cause: &models::LikelyCause, currency: Currency) -> String {
+    match cause {
+        models::LikelyCause::Missing { amount, misdated } => {
+            let missing = format!(
+                "missing transaction of {}",
+                Money::new(*amount, currency)
+            );
+            if misdated.is_empty() {
+                missing
+            } else {
+                let ids = misdated
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("{} (misdated? {})", missing, ids)
+            }
+        }
+        models::LikelyCause::Duplicate {
+            original,
+            duplicate,
+        } => format!("{} duplicates {}", duplicate, original),
+        models::LikelyCause::SignFlip { transaction } => {
+            format!("sign of {} flipped", transaction)
+        }
+    }
+}
+
+async fn discrepancies(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let repository = DatabaseRepository::new(db.clone());
+    let currencies = repository
+        .accounts()
+        .await?
+        .into_iter()
+        .map(|account| (account.name, account.currency))
+        .collect::<HashMap<String, Currency>>();
+    let records = reconciliation::discrepancies(db)
+        .await?
+        .into_iter()
+        .map(|discrepancy| {
+            let models::Discrepancy { interval, causes } = discrepancy;
+            let currency = currencies
+                .get(&interval.account)
+                .copied()
+                .unwrap_or_default();
+            let money = |amount: i64| Money::new(amount, currency);
+            DiscrepancyRecord {
+                snapshot: interval.snapshot,
+                start_date: display::date(&interval.start_date.into()),
+                end_date: display::date(&interval.end_date.into()),
+                calculated: money(interval.calculated),
+                expected: money(interval.expected),
+                delta: money(interval.delta()),
+                causes: causes
+                    .iter()
+                    .map(|cause| describe_cause(cause, currency))
+                    .collect::<Vec<String>>()
+                    .join("; "),
+                account: interval.account,
+            }
+        })
+        .collect::<Vec<DiscrepancyRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+async fn reconcile(
+    db: &DatabaseConnection,
+    snapshot: i32,
+    accept_delta: bool,
+) -> anyhow::Result<()> {
+    let reconciliation = if accept_delta {
+        reconciliation::adjust(db, snapshot).await?
+    } else {
+        reconciliation::reconcile(db, snapshot).await?
+    };
+    if let Some(adjustment) = reconciliation.adjustment {
+        println!("Recorded adjustment transaction {}", adjustment);
+    }
+    println!(
+        "Reconciled {} through {}",
+        reconciliation.account,
+        display::date(&reconciliation.end_date.into())
+    );
+    Ok(())
+}
+
+async fn list_reconciliations(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let records = reconciliation::list(db)
+        .await?
+        .into_iter()
+        .map(|reconciliation| reconciliation.into())
+        .collect::<Vec<ReconciliationRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Public Interface
 ////
@@ -110,15 +296,55 @@ async fn verify(db: &DatabaseConnection) -> anyhow::Result<()> {
 #[derive(Subcommand)]
 pub(crate) enum Verb {
     /// Verify the consistency of any balance snapshots by recalculating them.
-    Verify,
+    Verify {
+        /// Also report discrepancies in this currency (e.g. USD)
+        #[clap(short, long, value_parser)]
+        currency: Option<Currency>,
+    },
+
+    /// List the intervals between balance snapshots whose transactions don't
+    /// add up to the later snapshot, with their likely causes
+    Discrepancies,
+
+    /// Mark the interval ending at a balance snapshot as reconciled, locking
+    /// its transactions against edits
+    Reconcile {
+        /// The id of the snapshot that ends the interval
+        snapshot: i32,
+
+        /// Record an adjustment transaction for any difference between the
+        /// transactions and the snapshot, instead of failing
+        #[clap(long)]
+        accept_delta: bool,
+    },
+
+    /// Unlock the interval ending at a balance snapshot
+    Unreconcile {
+        /// The id of the snapshot that ends the interval
+        snapshot: i32,
+    },
+
+    /// List the reconciled intervals
+    Reconciliations,
 }
 
 pub(crate) async fn op(
     verb: &Verb,
     db: &DatabaseConnection,
 ) -> anyhow::Result<()> {
+    let repository = DatabaseRepository::new(db.clone());
     match &verb {
-        Verb::Verify => verify(db).await,
+        Verb::Verify { currency } => verify(&repository, *currency).await,
+        Verb::Discrepancies => discrepancies(db).await,
+        Verb::Reconcile {
+            snapshot,
+            accept_delta,
+        } => reconcile(db, *snapshot, *accept_delta).await,
+        Verb::Unreconcile { snapshot } => {
+            reconciliation::unreconcile(db, *snapshot).await?;
+            Ok(())
+        }
+        Verb::Reconciliations => list_reconciliations(db).await,
     }
 }
 
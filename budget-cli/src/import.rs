@@ -0,0 +1,135 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            import.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     CLI frontend for the record of imports in the
+//                  import_batches table.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_backend_lib::currency::account_currencies;
+use budget_backend_lib::import_batch;
+use budget_models::{display, models};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use table_iter::prelude::*;
+
+use crate::table;
+use crate::transaction::TransactionListRecord;
+
+#[derive(Fields, FieldNames)]
+struct ImportBatchRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "File")]
+    filename: String,
+    #[fields(rename = "Hash")]
+    hash: String,
+    #[fields(rename = "Imported")]
+    imported_at: String,
+    #[fields(rename = "Type")]
+    transaction_type: String,
+    #[fields(rename = "Rows")]
+    row_count: i32,
+}
+
+impl From<models::ImportBatch> for ImportBatchRecord {
+    fn from(value: models::ImportBatch) -> Self {
+        ImportBatchRecord {
+            id: value.id,
+            filename: value.filename,
+            // The full digest is too wide for a table.
+            hash: value.hash.chars().take(12).collect(),
+            imported_at: display::date(&value.imported_at.into()),
+            transaction_type: format!("{:?}", value.transaction_type),
+            row_count: value.row_count,
+        }
+    }
+}
+
+async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let records = import_batch::list(db)
+        .await?
+        .into_iter()
+        .map(|batch| batch.into())
+        .collect::<Vec<ImportBatchRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+async fn show(id: i32, db: &DatabaseConnection) -> anyhow::Result<()> {
+    let (batch, transactions) = import_batch::get(db, id).await?;
+    println!("File: {}", &batch.filename);
+    println!("SHA-256: {}", &batch.hash);
+    println!();
+    let currencies = account_currencies(db).await?;
+    let records = transactions
+        .into_iter()
+        .map(|transaction| {
+            TransactionListRecord::new(transaction, &currencies)
+        })
+        .collect::<Vec<TransactionListRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+async fn undo(id: i32, db: &DatabaseConnection) -> anyhow::Result<()> {
+    let count = import_batch::undo(db, id).await?;
+    println!("Removed import batch {} and its {} transactions", id, count);
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+#[derive(Subcommand)]
+pub(crate) enum Verb {
+    /// List every import, most recent first
+    List,
+
+    /// Show the transactions created by an import
+    Show {
+        /// The import batch ID
+        #[clap(value_parser)]
+        id: i32,
+    },
+
+    /// Remove exactly the transactions created by an import
+    Undo {
+        /// The import batch ID
+        #[clap(value_parser)]
+        id: i32,
+    },
+}
+
+pub(crate) async fn op(
+    verb: &Verb,
+    db: &DatabaseConnection,
+) -> anyhow::Result<()> {
+    match &verb {
+        Verb::List => list(db).await,
+        Verb::Show { id } => show(*id, db).await,
+        Verb::Undo { id } => undo(*id, db).await,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
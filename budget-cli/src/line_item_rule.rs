@@ -0,0 +1,169 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            line_item_rule.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     CLI frontend for the rules in the line_item_rules table.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_backend_lib::currency::account_currencies;
+use budget_backend_lib::line_item_rule;
+use budget_models::{Currency, LineItemRule, Money, NewLineItemRule};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use table_iter::prelude::*;
+
+use crate::table;
+
+#[derive(Fields, FieldNames)]
+struct LineItemRuleRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Priority")]
+    priority: i32,
+    #[fields(rename = "Line Item")]
+    line_item: String,
+    #[fields(rename = "Account", with = "table_iter::display::option")]
+    account: Option<String>,
+    #[fields(rename = "Pattern", with = "table_iter::display::option")]
+    pattern: Option<String>,
+    #[fields(rename = "Contains", with = "table_iter::display::option")]
+    contains: Option<String>,
+    #[fields(rename = "Amount", with = "table_iter::display::option")]
+    amount: Option<Money>,
+}
+
+impl LineItemRuleRecord {
+    // The amount is in the currency of the rule's account. A rule for every
+    // account takes its amount in the default currency, as it was parsed.
+    fn new(
+        value: LineItemRule,
+        currencies: &HashMap<String, Currency>,
+    ) -> Self {
+        let currency = value
+            .account
+            .as_ref()
+            .and_then(|account| currencies.get(account).copied())
+            .unwrap_or_default();
+        LineItemRuleRecord {
+            id: value.id,
+            priority: value.priority,
+            line_item: value.line_item,
+            account: value.account,
+            pattern: value.pattern,
+            contains: value.contains,
+            amount: value.amount.map(|amount| Money::new(amount, currency)),
+        }
+    }
+}
+
+async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let currencies = account_currencies(db).await?;
+    let records = line_item_rule::list(db)
+        .await?
+        .into_iter()
+        .map(|rule| LineItemRuleRecord::new(rule, &currencies))
+        .collect::<Vec<LineItemRuleRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+#[derive(Subcommand)]
+pub(crate) enum Verb {
+    /// List line item rules, in the order that they're tried
+    List,
+
+    /// Create a rule which assigns a line item to imported transactions
+    /// matching every one of the given conditions
+    Create {
+        /// The line item to assign
+        #[clap(value_parser)]
+        line_item: String,
+
+        /// Rules with a lower priority are tried first
+        #[clap(short, long, value_parser, default_value_t = 0)]
+        priority: i32,
+
+        /// Match transactions on this account
+        #[clap(short, long, value_parser)]
+        account: Option<String>,
+
+        /// Match descriptions matching this regular expression
+        #[clap(long, value_parser)]
+        pattern: Option<String>,
+
+        /// Match descriptions containing this text, ignoring case
+        #[clap(short, long, value_parser)]
+        contains: Option<String>,
+
+        /// Match transactions of exactly this amount, e.g. -15.99
+        #[clap(long, value_parser, allow_hyphen_values = true)]
+        amount: Option<Money>,
+    },
+
+    /// Delete a line item rule
+    Delete {
+        /// The rule ID
+        #[clap(value_parser)]
+        id: i32,
+    },
+}
+
+pub(crate) async fn op(
+    verb: &Verb,
+    db: &DatabaseConnection,
+) -> anyhow::Result<()> {
+    match &verb {
+        Verb::List => list(db).await,
+        Verb::Create {
+            line_item,
+            priority,
+            account,
+            pattern,
+            contains,
+            amount,
+        } => {
+            let rule = NewLineItemRule {
+                priority: *priority,
+                line_item: line_item.clone(),
+                account: account.clone(),
+                pattern: pattern.clone(),
+                contains: contains.clone(),
+                amount: amount.map(|amount| amount.into()),
+            };
+            let rule = line_item_rule::create(db, rule).await?;
+            println!("Created line item rule {}", rule.id);
+            Ok(())
+        }
+        Verb::Delete { id } => {
+            line_item_rule::delete(db, *id).await?;
+            Ok(())
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         09/22/2022
 //
-// LAST EDITED:     11/28/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -29,8 +29,14 @@ use sea_orm::Database;
 use std::env;
 
 mod balance_snapshot;
+mod import;
+mod line_item_rule;
+mod one_time_budget;
+mod payee;
 mod periodic_budget;
+mod recurring_transaction;
 mod table;
+mod tag;
 mod transaction;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -52,6 +58,12 @@ enum Object {
         verb: periodic_budget::Verb,
     },
 
+    /// Actions available on the set of one-time budgets
+    OneTimeBudget {
+        #[command(subcommand)]
+        verb: one_time_budget::Verb,
+    },
+
     /// Actions available on the set of transactions, real and planned
     Transaction {
         /// The type of transaction to act on
@@ -63,11 +75,41 @@ enum Object {
         verb: transaction::Verb,
     },
 
+    /// Actions available on the set of recurring transactions
+    RecurringTransaction {
+        #[command(subcommand)]
+        verb: recurring_transaction::Verb,
+    },
+
+    /// Actions available on the rules for categorizing imported transactions
+    LineItemRule {
+        #[command(subcommand)]
+        verb: line_item_rule::Verb,
+    },
+
+    /// Actions available on the set of payees
+    Payee {
+        #[command(subcommand)]
+        verb: payee::Verb,
+    },
+
+    /// Actions available on the set of tags
+    Tag {
+        #[command(subcommand)]
+        verb: tag::Verb,
+    },
+
     /// Actions available on the set of balance snapshots
     BalanceSnapshot {
         #[command(subcommand)]
         verb: balance_snapshot::Verb,
     },
+
+    /// Actions available on the record of transaction imports
+    Import {
+        #[command(subcommand)]
+        verb: import::Verb,
+    },
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -83,13 +125,21 @@ async fn main() -> anyhow::Result<()> {
         Object::PeriodicBudget { verb } => {
             periodic_budget::op(verb, &db).await
         }
+        Object::OneTimeBudget { verb } => one_time_budget::op(verb, &db).await,
         Object::Transaction {
             transaction_type,
             verb,
         } => transaction::op(verb, *transaction_type, &db).await,
+        Object::RecurringTransaction { verb } => {
+            recurring_transaction::op(verb, &db).await
+        }
+        Object::LineItemRule { verb } => line_item_rule::op(verb, &db).await,
+        Object::Payee { verb } => payee::op(verb, &db).await,
+        Object::Tag { verb } => tag::op(verb, &db).await,
         Object::BalanceSnapshot { verb } => {
             balance_snapshot::op(verb, &db).await
         }
+        Object::Import { verb } => import::op(verb, &db).await,
     }
 }
 
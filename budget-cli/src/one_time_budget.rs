@@ -0,0 +1,190 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            one_time_budget.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Operations done on the set of one-time budgets.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_backend_lib::one_time_budget;
+use budget_models::{Money, NewOneTimeBudget, OneTimeBudgetStatus};
+use chrono::{Local, NaiveDate, NaiveTime};
+use clap::{Subcommand, ValueEnum};
+use sea_orm::DatabaseConnection;
+use table_iter::prelude::*;
+
+use crate::table;
+
+#[derive(Fields, FieldNames)]
+struct OneTimeBudgetRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Description")]
+    description: String,
+    #[fields(rename = "Status")]
+    status: String,
+    #[fields(rename = "Target")]
+    target: Money,
+    #[fields(rename = "Funded")]
+    funded: Money,
+    #[fields(rename = "Spent")]
+    spent: Money,
+    #[fields(rename = "Remaining")]
+    remaining: Money,
+    #[fields(rename = "Planned")]
+    planned: Money,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum Status {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+impl Into<OneTimeBudgetStatus> for Status {
+    fn into(self) -> OneTimeBudgetStatus {
+        match self {
+            Status::Active => OneTimeBudgetStatus::Active,
+            Status::Completed => OneTimeBudgetStatus::Completed,
+            Status::Cancelled => OneTimeBudgetStatus::Cancelled,
+        }
+    }
+}
+
+fn parse_date(value: &str) -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+    Ok(NaiveDate::parse_from_str(value, "%m/%d/%y")?
+        .and_time(NaiveTime::default())
+        .and_local_timezone(Local)
+        .single()
+        .unwrap()
+        .into())
+}
+
+async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let mut records = Vec::new();
+    for budget in one_time_budget::list(db).await? {
+        let progress = one_time_budget::progress(db, &budget).await?;
+        records.push(OneTimeBudgetRecord {
+            id: budget.id,
+            description: budget.description,
+            status: format!("{:?}", budget.status),
+            target: progress.target,
+            funded: progress.funded,
+            spent: progress.spent,
+            remaining: progress.remaining(),
+            planned: progress.planned,
+        });
+    }
+
+    table::print(&records);
+    Ok(())
+}
+
+async fn create(
+    db: &DatabaseConnection,
+    description: &str,
+    target: &str,
+    account: &Option<String>,
+    start_date: &Option<String>,
+    end_date: &Option<String>,
+) -> anyhow::Result<()> {
+    let budget = NewOneTimeBudget {
+        description: description.to_string(),
+        target: target.parse::<Money>()?.into(),
+        account: account.clone(),
+        start_date: start_date.as_deref().map(parse_date).transpose()?,
+        end_date: end_date.as_deref().map(parse_date).transpose()?,
+    };
+    let budget = one_time_budget::create(db, budget).await?;
+    println!("Created one-time budget {}", budget.id);
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+#[derive(Subcommand)]
+pub(crate) enum Verb {
+    /// List one-time budgets, along with their progress
+    List,
+
+    /// Create a one-time budget
+    Create {
+        /// A description of the budget, e.g. "Kitchen remodel"
+        #[clap(value_parser)]
+        description: String,
+
+        /// The target amount of the budget, e.g. 15,000.00
+        #[clap(value_parser)]
+        target: String,
+
+        /// Only count transactions on this account towards progress
+        #[clap(short, long, value_parser)]
+        account: Option<String>,
+
+        /// The first day of the budget (mm/dd/yy)
+        #[clap(short, long, value_parser)]
+        start_date: Option<String>,
+
+        /// The last day of the budget (mm/dd/yy)
+        #[clap(short, long, value_parser)]
+        end_date: Option<String>,
+    },
+
+    /// Change the status of a one-time budget
+    SetStatus {
+        /// The budget ID
+        #[clap(value_parser)]
+        budget: i32,
+
+        /// The new status
+        #[arg(value_enum)]
+        #[clap(value_parser)]
+        status: Status,
+    },
+}
+
+pub(crate) async fn op(
+    verb: &Verb,
+    db: &DatabaseConnection,
+) -> anyhow::Result<()> {
+    match verb {
+        Verb::List => list(db).await,
+        Verb::Create {
+            description,
+            target,
+            account,
+            start_date,
+            end_date,
+        } => {
+            create(db, description, target, account, start_date, end_date)
+                .await
+        }
+        Verb::SetStatus { budget, status } => {
+            one_time_budget::set_status(db, *budget, (*status).into()).await?;
+            Ok(())
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
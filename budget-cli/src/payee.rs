@@ -0,0 +1,205 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            payee.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     CLI frontend for payees and their aliases.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_backend_lib::payee;
+use budget_models::{NewPayee, NewPayeeAlias, Payee, PayeeAlias};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use table_iter::prelude::*;
+
+use crate::table;
+
+#[derive(Fields, FieldNames)]
+struct PayeeRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Name")]
+    name: String,
+    #[fields(rename = "Aliases")]
+    aliases: String,
+}
+
+impl From<Payee> for PayeeRecord {
+    fn from(value: Payee) -> Self {
+        PayeeRecord {
+            id: value.id,
+            name: value.name,
+            aliases: value
+                .aliases
+                .into_iter()
+                .map(|alias| alias.pattern)
+                .collect::<Vec<String>>()
+                .join(", "),
+        }
+    }
+}
+
+#[derive(Fields, FieldNames)]
+struct PayeeAliasRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Pattern")]
+    pattern: String,
+}
+
+impl From<PayeeAlias> for PayeeAliasRecord {
+    fn from(value: PayeeAlias) -> Self {
+        PayeeAliasRecord {
+            id: value.id,
+            pattern: value.pattern,
+        }
+    }
+}
+
+async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let records = payee::list(db)
+        .await?
+        .into_iter()
+        .map(|payee| payee.into())
+        .collect::<Vec<PayeeRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+async fn show(name: &str, db: &DatabaseConnection) -> anyhow::Result<()> {
+    let records = payee::find(db, name)
+        .await?
+        .aliases
+        .into_iter()
+        .map(|alias| alias.into())
+        .collect::<Vec<PayeeAliasRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+#[derive(Subcommand)]
+pub(crate) enum Verb {
+    /// List payees, and the patterns of their aliases
+    List,
+
+    /// Show the aliases of a payee
+    Show {
+        /// The name of the payee
+        #[clap(value_parser)]
+        name: String,
+    },
+
+    /// Create a payee
+    Create {
+        /// The canonical name of the payee, e.g. Kroger
+        #[clap(value_parser)]
+        name: String,
+
+        /// A regular expression matching the descriptions of the payee's
+        /// transactions, ignoring case. May be given more than once
+        #[clap(short, long, value_parser)]
+        alias: Vec<String>,
+    },
+
+    /// Add an alias to a payee
+    Alias {
+        /// The name of the payee
+        #[clap(value_parser)]
+        name: String,
+
+        /// A regular expression matching the descriptions of the payee's
+        /// transactions, ignoring case
+        #[clap(value_parser)]
+        pattern: String,
+    },
+
+    /// Delete an alias of a payee
+    Unalias {
+        /// The alias ID
+        #[clap(value_parser)]
+        id: i32,
+    },
+
+    /// Merge a payee into another, moving its transactions and aliases
+    Merge {
+        /// The name of the payee to merge, which is deleted
+        #[clap(value_parser)]
+        from: String,
+
+        /// The name of the payee to merge it into
+        #[clap(value_parser)]
+        into: String,
+    },
+
+    /// Assign payees to the transactions which don't have one, by the
+    /// aliases of the payees
+    Normalize,
+}
+
+pub(crate) async fn op(
+    verb: &Verb,
+    db: &DatabaseConnection,
+) -> anyhow::Result<()> {
+    match &verb {
+        Verb::List => list(db).await,
+        Verb::Show { name } => show(name, db).await,
+        Verb::Create { name, alias } => {
+            let payee = NewPayee {
+                name: name.clone(),
+                aliases: alias.clone(),
+            };
+            let payee = payee::create(db, payee).await?;
+            println!("Created payee {}", payee.id);
+            Ok(())
+        }
+        Verb::Alias { name, pattern } => {
+            let alias = NewPayeeAlias {
+                payee: payee::find(db, name).await?.id,
+                pattern: pattern.clone(),
+            };
+            let alias = payee::add_alias(db, alias).await?;
+            println!("Created payee alias {}", alias.id);
+            Ok(())
+        }
+        Verb::Unalias { id } => {
+            payee::delete_alias(db, *id).await?;
+            Ok(())
+        }
+        Verb::Merge { from, into } => {
+            let from = payee::find(db, from).await?;
+            let into = payee::find(db, into).await?;
+            payee::merge(db, from.id, into.id).await?;
+            println!("Merged payee {} into {}", from.name, into.name);
+            Ok(())
+        }
+        Verb::Normalize => {
+            let count = payee::normalize(db).await?;
+            println!("Assigned payees to {} transactions", count);
+            Ok(())
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
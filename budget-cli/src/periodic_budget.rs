@@ -7,7 +7,7 @@
 //
 // CREATED:         11/10/2022
 //
-// LAST EDITED:     11/17/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,7 +24,12 @@
 // limitations under the License.
 ////
 
+use budget_backend_lib::balance;
+use budget_backend_lib::currency::account_currencies;
 use budget_backend_lib::prelude::*;
+use budget_models::calculation::{Calculation, CalculationExt};
+use budget_models::total::DistinctCount;
+use budget_models::{Money, NewInitialBalance};
 use clap::Subcommand;
 use sea_orm::prelude::*;
 use sea_orm::DatabaseConnection;
@@ -51,20 +56,24 @@ async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
         .filter(planned_transactions::Column::PeriodicBudget.is_in(budget_ids))
         .all(db)
         .await?;
+    // Split transactions have several rows, so count each transaction only
+    // once.
+    let mut counts = DistinctCount::default()
+        .map_input(|plan: &planned_transactions::Model| plan.transaction)
+        .group_by(|plan| plan.periodic_budget);
+    counts.apply_all(planned_transactions.iter());
+    let counts = counts.calculate();
+
     let budgets = budgets
         .iter()
-        .map(|budget| {
-            let number_of_planned_transactions = planned_transactions
-                .iter()
-                .filter(|plan| plan.periodic_budget == budget.id)
-                .collect::<Vec<&planned_transactions::Model>>()
-                .len();
-            PeriodicBudgetRecord {
-                id: budget.id,
-                start_date: budget.start_date,
-                end_date: budget.end_date,
-                planned_transactions: number_of_planned_transactions,
-            }
+        .map(|budget| PeriodicBudgetRecord {
+            id: budget.id,
+            start_date: budget.start_date,
+            end_date: budget.end_date,
+            planned_transactions: counts
+                .get(&Some(budget.id))
+                .copied()
+                .unwrap_or_default(),
         })
         .collect::<Vec<PeriodicBudgetRecord>>();
 
@@ -72,6 +81,67 @@ async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[derive(Fields, FieldNames)]
+struct OpeningBalanceRecord {
+    #[fields(rename = "Budget")]
+    budget: i32,
+    #[fields(rename = "Account")]
+    account: String,
+    #[fields(rename = "Opening Balance")]
+    balance: Money,
+    #[fields(rename = "Adjustment", with = "table_iter::display::option")]
+    adjustment: Option<Money>,
+}
+
+async fn opening_balances(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let currencies = account_currencies(db).await?;
+    let records = balance::opening_balances(db)
+        .await?
+        .into_iter()
+        .map(|opening| {
+            let currency = currencies
+                .get(&opening.account)
+                .copied()
+                .unwrap_or_default();
+            OpeningBalanceRecord {
+                budget: opening.budget,
+                account: opening.account.clone(),
+                balance: Money::new(opening.balance, currency),
+                adjustment: opening
+                    .adjustment()
+                    .map(|amount| Money::new(amount, currency)),
+            }
+        })
+        .collect::<Vec<OpeningBalanceRecord>>();
+
+    table::print(&records);
+    Ok(())
+}
+
+async fn record_adjustments(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let updated = balance::record_adjustments(db).await?;
+    println!(
+        "Updated the adjustment of {} initial balances",
+        updated.len()
+    );
+    Ok(())
+}
+
+async fn set_initial_balance(
+    db: &DatabaseConnection,
+    budget: i32,
+    account: &str,
+    balance: &str,
+) -> anyhow::Result<()> {
+    let balance = NewInitialBalance {
+        account: account.to_string(),
+        budget,
+        balance: balance.parse::<Money>()?.into(),
+    };
+    balance::set_initial_balance(db, balance).await?;
+    Ok(())
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Public Interface
 ////
@@ -80,6 +150,28 @@ async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
 pub(crate) enum Verb {
     /// List periodic budgets in the database
     List,
+
+    /// Show the opening balance of each account in each budget
+    OpeningBalances,
+
+    /// Record the adjustment of each initial balance from the balance carried
+    /// from the previous budget, e.g. after transactions were imported
+    RecordAdjustments,
+
+    /// Set the balance of an account at the start of a budget
+    SetInitialBalance {
+        /// The budget ID
+        #[clap(value_parser)]
+        budget: i32,
+
+        /// The account
+        #[clap(value_parser)]
+        account: String,
+
+        /// The balance, e.g. 1,234.56
+        #[clap(value_parser, allow_hyphen_values = true)]
+        balance: String,
+    },
 }
 
 pub(crate) async fn op(
@@ -88,6 +180,13 @@ pub(crate) async fn op(
 ) -> anyhow::Result<()> {
     match &verb {
         Verb::List => list(db).await,
+        Verb::OpeningBalances => opening_balances(db).await,
+        Verb::RecordAdjustments => record_adjustments(db).await,
+        Verb::SetInitialBalance {
+            budget,
+            account,
+            balance,
+        } => set_initial_balance(db, *budget, account, balance).await,
     }
 }
 
@@ -0,0 +1,128 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            recurring_transaction.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Operations done on the set of recurring transactions.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::HashMap;
+
+use budget_backend_lib::currency::account_currencies;
+use budget_backend_lib::recurrence;
+use budget_models::{display, Currency, Money, RecurringTransaction};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use table_iter::prelude::*;
+
+use crate::table;
+
+#[derive(Fields, FieldNames)]
+struct RecurringTransactionRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Summary")]
+    summary: String,
+    #[fields(rename = "From Account", with = "table_iter::display::option")]
+    from_account: Option<String>,
+    #[fields(rename = "To Account", with = "table_iter::display::option")]
+    to_account: Option<String>,
+    #[fields(rename = "Amount")]
+    amount: Money,
+    #[fields(rename = "Line Item")]
+    line_item: String,
+    #[fields(rename = "Next Date", with = "table_iter::display::option")]
+    next_date: Option<String>,
+}
+
+impl RecurringTransactionRecord {
+    // The amount is in the currency of the schedule's accounts.
+    fn new(
+        value: RecurringTransaction,
+        currencies: &HashMap<String, Currency>,
+    ) -> Self {
+        let today = chrono::Utc::now();
+        let currency = value
+            .from_account
+            .iter()
+            .chain(value.to_account.iter())
+            .find_map(|account| currencies.get(account).copied())
+            .unwrap_or_default();
+        RecurringTransactionRecord {
+            id: value.id,
+            next_date: value
+                .rule
+                .occurrences()
+                .find(|date| *date >= today)
+                .map(|date| display::date(&date.into())),
+            summary: value.summary,
+            from_account: value.from_account,
+            to_account: value.to_account,
+            amount: Money::new(value.amount, currency),
+            line_item: value.line_item,
+        }
+    }
+}
+
+async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let currencies = account_currencies(db).await?;
+    let records = recurrence::list(db)
+        .await?
+        .into_iter()
+        .map(|recurring| {
+            RecurringTransactionRecord::new(recurring, &currencies)
+        })
+        .collect::<Vec<RecurringTransactionRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+async fn generate(db: &DatabaseConnection) -> anyhow::Result<()> {
+    let created = recurrence::generate_all(db).await?;
+    println!("Generated {} planned transactions", created.len());
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+#[derive(Subcommand)]
+pub(crate) enum Verb {
+    /// List recurring transactions in the database
+    List,
+
+    /// Generate planned transactions for every periodic budget. Occurrences
+    /// which were already generated are not generated again.
+    Generate,
+}
+
+pub(crate) async fn op(
+    verb: &Verb,
+    db: &DatabaseConnection,
+) -> anyhow::Result<()> {
+    match &verb {
+        Verb::List => list(db).await,
+        Verb::Generate => generate(db).await,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
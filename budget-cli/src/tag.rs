@@ -0,0 +1,156 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            tag.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Operations done on the set of tags.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use budget_backend_lib::currency::account_currencies;
+use budget_backend_lib::tag;
+use budget_models::{display, Currency, Money, TagFilter, TransactionData};
+use clap::Subcommand;
+use sea_orm::DatabaseConnection;
+use table_iter::prelude::*;
+
+use crate::table;
+
+#[derive(Fields, FieldNames)]
+struct TaggedTransactionRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Summary")]
+    summary: String,
+    #[fields(rename = "Date")]
+    date: String,
+    #[fields(rename = "Account")]
+    account: String,
+    #[fields(rename = "Amount")]
+    amount: Money,
+}
+
+impl TaggedTransactionRecord {
+    fn new(value: TransactionData, currency: Currency) -> Self {
+        TaggedTransactionRecord {
+            id: value.id,
+            summary: value.summary,
+            date: display::date(&value.date.into()),
+            account: value.account,
+            amount: Money::new(value.amount, currency),
+        }
+    }
+}
+
+async fn list(db: &DatabaseConnection) -> anyhow::Result<()> {
+    for tag in tag::list(db).await? {
+        println!("{}", &tag.tag);
+    }
+    Ok(())
+}
+
+async fn find(
+    db: &DatabaseConnection,
+    tags: &[String],
+    all: bool,
+) -> anyhow::Result<()> {
+    let filter = if all {
+        TagFilter::All(tags.to_vec())
+    } else {
+        TagFilter::Any(tags.to_vec())
+    };
+    let currencies = account_currencies(db).await?;
+    let records = tag::find_transactions(db, &filter)
+        .await?
+        .into_iter()
+        .map(|transaction| {
+            let currency = currencies
+                .get(&transaction.account)
+                .copied()
+                .unwrap_or_default();
+            TaggedTransactionRecord::new(transaction, currency)
+        })
+        .collect::<Vec<TaggedTransactionRecord>>();
+    table::print(&records);
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Public Interface
+////
+
+#[derive(Subcommand)]
+pub(crate) enum Verb {
+    /// List all tags
+    List,
+
+    /// Attach a tag to a transaction, creating the tag if necessary
+    Attach {
+        /// The transaction ID
+        #[clap(value_parser)]
+        transaction: i32,
+
+        /// The tag, e.g. "reimbursable"
+        #[clap(value_parser)]
+        tag: String,
+    },
+
+    /// Remove a tag from a transaction
+    Detach {
+        /// The transaction ID
+        #[clap(value_parser)]
+        transaction: i32,
+
+        /// The tag
+        #[clap(value_parser)]
+        tag: String,
+    },
+
+    /// List the transactions which have any of the tags
+    Find {
+        /// Only list transactions which have all of the tags
+        #[clap(short, long)]
+        all: bool,
+
+        /// The tags to search for
+        #[clap(value_parser, required = true)]
+        tags: Vec<String>,
+    },
+}
+
+pub(crate) async fn op(
+    verb: &Verb,
+    db: &DatabaseConnection,
+) -> anyhow::Result<()> {
+    match verb {
+        Verb::List => list(db).await,
+        Verb::Attach { transaction, tag } => {
+            tag::attach(db, *transaction, tag).await?;
+            Ok(())
+        }
+        Verb::Detach { transaction, tag } => {
+            tag::detach(db, *transaction, tag).await?;
+            Ok(())
+        }
+        Verb::Find { all, tags } => find(db, tags, *all).await,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
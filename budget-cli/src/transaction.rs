@@ -8,7 +8,7 @@
 //
 // CREATED:         11/11/2022
 //
-// LAST EDITED:     11/19/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,19 +25,33 @@
 // limitations under the License.
 ////
 
-use budget_backend_lib::error::{MissingBudgetError, MissingLineItemError};
-use budget_backend_lib::prelude::*;
-use budget_backend_lib::transaction;
-use budget_models::models::{self, CondensedTransaction};
+use bankstatement::fecccu::{self, FecccuParser};
+use bankstatement::generic::CsvParser;
+use bankstatement::{Statement, StatementParser};
+use budget_backend_lib::error::Error;
+use budget_backend_lib::repository::{DatabaseRepository, Repository};
+use budget_backend_lib::{suggestion, transaction};
+use budget_models::models::{
+    self, Allocation, CondensedTransaction, ImportMatch, NewBalanceSnapshot,
+    NewImportBatch, NewTransaction, Transaction, TransactionFilter,
+    ValidationError,
+};
+use budget_models::normalization::Payees;
+use budget_models::rules::LineItemRules;
+use budget_models::{display, Currency, Money};
 use chrono::{
     naive::{NaiveDate, NaiveTime},
-    offset::Local,
+    offset::{FixedOffset, Local, Utc},
+    DateTime, Duration,
 };
 use clap::{Subcommand, ValueEnum};
-use sea_orm::prelude::*;
 use sea_orm::DatabaseConnection;
 use serde::Deserialize;
-use std::fs::File;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use table_iter::prelude::*;
 
 use crate::table;
 
@@ -62,6 +76,22 @@ impl Into<models::TransactionType> for TransactionType {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Format
+// The formats that transactions can be imported from.
+////
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum Format {
+    /// A CSV table with the columns of a condensed transaction
+    Budget,
+    /// A FECCCU statement, as a PDF or as converted by pdftotext -layout
+    Fecccu,
+    /// A CSV export with date, description and amount columns, by default
+    /// the first three
+    Csv,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // TransactionRecord
 ////
@@ -70,8 +100,9 @@ impl Into<models::TransactionType> for TransactionType {
 struct TransactionRecord {
     #[serde(rename = "Summary")]
     summary: String,
-    #[serde(rename = "Line Item")]
-    line_item: String,
+    // Missing for bank exports, which are categorized by the line item rules.
+    #[serde(rename = "Line Item", default)]
+    line_item: Option<String>,
     #[serde(rename = "Date")]
     date: String,
     #[serde(rename = "From Account")]
@@ -79,117 +110,593 @@ struct TransactionRecord {
     #[serde(rename = "To Account")]
     to_account: Option<String>,
     #[serde(rename = "Amount")]
-    amount: f64,
+    amount: String,
+    #[serde(rename = "One-Time Budget", default)]
+    one_time_budget: Option<i32>,
+    // An id assigned to the line by the source, e.g. by the bank.
+    #[serde(rename = "Id", default)]
+    source_id: Option<String>,
+}
+
+// The "Line Item" column contains either the name of a single line item, or
+// the allocations of a split transaction, e.g. "Groceries=45.10; Gifts=14.89"
+fn parse_line_items(
+    value: &str,
+    amount: Money,
+) -> anyhow::Result<Vec<Allocation>> {
+    if !value.contains('=') {
+        return Ok(vec![Allocation {
+            line_item: value.trim().to_string(),
+            amount: amount.into(),
+        }]);
+    }
+
+    value
+        .split(';')
+        .filter(|split| !split.trim().is_empty())
+        .map(|split| {
+            let (line_item, amount) =
+                split.split_once('=').ok_or_else(|| {
+                    anyhow::anyhow!("split {:?} has no amount", split.trim())
+                })?;
+            Ok(Allocation {
+                line_item: line_item.trim().to_string(),
+                amount: amount.parse::<Money>()?.into(),
+            })
+        })
+        .collect()
 }
 
 impl TryInto<CondensedTransaction> for TransactionRecord {
-    type Error = chrono::format::ParseError;
+    type Error = anyhow::Error;
     fn try_into(self) -> Result<CondensedTransaction, Self::Error> {
+        let amount = self.amount.parse::<Money>()?;
         Ok(CondensedTransaction {
             summary: self.summary,
-            line_item: self.line_item,
+            line_items: match self.line_item.as_deref().map(str::trim) {
+                Some(line_item) if !line_item.is_empty() => {
+                    parse_line_items(line_item, amount)?
+                }
+                _ => Vec::new(),
+            },
             from_account: self.from_account,
             to_account: self.to_account,
-            amount: self.amount.into(),
-            date: NaiveDate::parse_from_str(&self.date, "%m/%d/%y")?
-                .and_time(NaiveTime::default())
-                .and_local_timezone(Local)
-                .single()
-                .unwrap()
-                .into(),
+            amount,
+            one_time_budget: self.one_time_budget,
+            source_id: self.source_id,
+            date: local_date(NaiveDate::parse_from_str(
+                &self.date, "%m/%d/%y",
+            )?),
             ..Default::default()
         })
     }
 }
 
-async fn read_transactions(
+// The start of the day, in the local timezone.
+fn local_date(date: NaiveDate) -> DateTime<FixedOffset> {
+    date.and_time(NaiveTime::default())
+        .and_local_timezone(Local)
+        .single()
+        .unwrap()
+        .into()
+}
+
+// Read a statement, in any format but Budget. CSV statements are read with
+// the given parser, which knows their columns.
+fn parse_statement(
     filename: &str,
-    db: &DatabaseConnection,
+    format: Format,
+    csv: &CsvParser,
+) -> anyhow::Result<Statement> {
+    let statement = match format {
+        Format::Fecccu if filename.ends_with(".pdf") => {
+            FecccuParser::new().parse(&fecccu::pdf_to_text(filename)?)?
+        }
+        Format::Fecccu => {
+            FecccuParser::new().parse(&fs::read_to_string(filename)?)?
+        }
+        Format::Csv => csv.parse(&fs::read_to_string(filename)?)?,
+        Format::Budget => unreachable!("budget files aren't statements"),
+    };
+    Ok(statement)
+}
+
+// The transactions of a statement, which are all on the account, and a
+// snapshot of the account's balance at the close of the statement.
+fn read_statement(
+    filename: &str,
+    format: Format,
+    csv: &CsvParser,
+    account: &str,
+    currency: Currency,
+) -> anyhow::Result<(Vec<CondensedTransaction>, Vec<NewBalanceSnapshot>)> {
+    let statement = parse_statement(filename, format, csv)?;
+    let records = statement
+        .transactions
+        .iter()
+        .map(|transaction| CondensedTransaction {
+            summary: transaction.description.clone(),
+            date: local_date(transaction.date),
+            from_account: (transaction.amount < 0)
+                .then(|| account.to_string()),
+            to_account: (transaction.amount >= 0).then(|| account.to_string()),
+            amount: Money::new(transaction.amount, currency),
+            ..Default::default()
+        })
+        .collect();
+
+    // A snapshot counts the transactions before its date, so the balance at
+    // the close of a day is recorded at the start of the next.
+    let snapshots = statement
+        .closing_balance()
+        .into_iter()
+        .map(|balance| NewBalanceSnapshot {
+            account: account.to_string(),
+            date: local_date(balance.date + Duration::days(1)).into(),
+            amount: balance.amount,
+        })
+        .collect();
+    Ok((records, snapshots))
+}
+
+// Read the transactions to import, and the balance snapshots of statements.
+async fn read_transactions<R: Repository>(
+    filename: &str,
+    format: Format,
+    csv: &CsvParser,
+    account: &Option<String>,
+    repository: &R,
     transaction_type: TransactionType,
-) -> anyhow::Result<Vec<CondensedTransaction>> {
+) -> anyhow::Result<(Vec<CondensedTransaction>, Vec<NewBalanceSnapshot>)> {
     // Read the input records into a vector
-    let mut records = csv::Reader::from_reader(File::open(filename)?)
-        .deserialize()
-        .collect::<Result<Vec<TransactionRecord>, _>>()?
-        .into_iter()
-        .map(|t| t.try_into())
-        .collect::<Result<Vec<CondensedTransaction>, _>>()?;
-    let dates = records
-        .iter()
-        .map(|r| r.date)
-        .collect::<Vec<DateTimeWithTimeZone>>();
-    let min_date = dates.iter().min().unwrap();
-    let max_date = dates.iter().max().unwrap();
-
-    let budgets = PeriodicBudgets::find()
-        .filter(periodic_budgets::Column::StartDate.lte(*min_date))
-        .filter(periodic_budgets::Column::EndDate.gte(*max_date))
-        .all(db)
-        .await?;
-
-    let line_items = LineItems::find().all(db).await?;
-    for mut record in &mut records {
-        let CondensedTransaction {
-            line_item, date, ..
-        } = record;
-        if line_items
+    let (mut records, snapshots) = match (format, transaction_type) {
+        (Format::Budget, _) => (
+            csv::Reader::from_reader(File::open(filename)?)
+                .deserialize()
+                .collect::<Result<Vec<TransactionRecord>, _>>()?
+                .into_iter()
+                .map(|t| t.try_into())
+                .collect::<Result<Vec<CondensedTransaction>, _>>()?,
+            Vec::new(),
+        ),
+        (_, TransactionType::Planned) => {
+            anyhow::bail!(
+                "Statements can only be imported as real transactions"
+            )
+        }
+        (_, TransactionType::Real) => {
+            let account = account.as_deref().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The account is required to import a statement"
+                )
+            })?;
+            let currency = account_currencies(repository)
+                .await?
+                .get(account)
+                .copied()
+                .ok_or_else(|| Error::not_found("account", account))?;
+            read_statement(filename, format, csv, account, currency)?
+        }
+    };
+    // Lines without an id are identified by their position among the
+    // otherwise identical lines of the file, which is stable across exports
+    // of overlapping date ranges.
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    for record in &mut records {
+        if record.source_id.is_none() {
+            let key = format!(
+                "{:?}|{:?}|{}|{}|{}",
+                record.from_account,
+                record.to_account,
+                record.date.date_naive(),
+                record.amount,
+                models::normalize_description(&record.summary)
+            );
+            let occurrence = occurrences.entry(key).or_default();
+            *occurrence += 1;
+            record.source_id = Some(format!("#{}", occurrence));
+        }
+    }
+
+    // Real transactions without a line item are categorized by the first rule
+    // that matches them, or else left uncategorized. Every transaction is
+    // given the payee of the first alias that matches its description.
+    let rules = LineItemRules::new(repository.line_item_rules().await?)?;
+    let payees = Payees::new(repository.payees().await?)?;
+    let budgets = repository.periodic_budgets().await?;
+    for record in &mut records {
+        if let TransactionType::Real = transaction_type {
+            rules.assign(record);
+        }
+        payees.assign(record);
+
+        // Transactions attached to a one-time budget don't need to fall
+        // within a periodic budget.
+        let date: DateTime<Utc> = record.date.into();
+        let periodic_budget = budgets
             .iter()
-            .find(|l| l.summary == *line_item)
-            .is_none()
-        {
-            return Err(MissingLineItemError::new(line_item).into());
+            .find(|&b| b.start_date <= date && b.end_date >= date)
+            .map(|b| b.id);
+        if periodic_budget.is_none() && record.one_time_budget.is_none() {
+            return Err(Error::not_found(
+                "periodic budget including",
+                record.date.format("%d %b %Y"),
+            )
+            .into());
         }
 
         record.transaction_type = transaction_type.into();
-        record.periodic_budget = Some(
-            budgets
+        record.periodic_budget = periodic_budget;
+    }
+    Ok((records, snapshots))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TransactionListRecord
+// One row per transaction. The legs of a linked series are condensed into a
+// single row, listing every account in the series.
+////
+
+#[derive(Fields, FieldNames)]
+pub(crate) struct TransactionListRecord {
+    #[fields(rename = "Id")]
+    id: i32,
+    #[fields(rename = "Summary")]
+    summary: String,
+    #[fields(rename = "Line Item")]
+    line_items: String,
+    #[fields(rename = "Date", with = "budget_models::display::date")]
+    date: DateTime<FixedOffset>,
+    #[fields(rename = "Accounts")]
+    accounts: String,
+    #[fields(rename = "Amount")]
+    amount: Money,
+    #[fields(rename = "Budget", with = "table_iter::display::option")]
+    periodic_budget: Option<i32>,
+}
+
+impl TransactionListRecord {
+    // The amount of the series is in the currency of its first leg's account.
+    pub(crate) fn new(
+        transaction: Transaction,
+        currencies: &HashMap<String, Currency>,
+    ) -> Self {
+        let legs = transaction.series.legs();
+        let metadata = transaction.metadata.metadata();
+        let currency = currencies
+            .get(&legs[0].account)
+            .copied()
+            .unwrap_or_default();
+        TransactionListRecord {
+            id: transaction.id,
+            summary: legs[0].summary.clone(),
+            line_items: display::allocations_in(
+                metadata
+                    .map(|m| m.allocations.as_slice())
+                    .unwrap_or_default(),
+                currency,
+            ),
+            date: legs[0].date.into(),
+            accounts: legs
                 .iter()
-                .find(|&b| b.start_date <= *date && b.end_date >= *date)
-                .ok_or_else(|| {
-                    MissingBudgetError::new(
-                        date.format("%d %b %Y").to_string(),
-                    )
-                })?
-                .id,
-        );
+                .map(|leg| leg.account.as_str())
+                .collect::<Vec<&str>>()
+                .join(", "),
+            amount: Money::new(transaction.series.amount(), currency),
+            periodic_budget: metadata.and_then(|m| m.periodic_budget),
+        }
     }
-    Ok(records)
+}
+
+// The currency of every account, by name.
+async fn account_currencies<R: Repository>(
+    repository: &R,
+) -> anyhow::Result<HashMap<String, Currency>> {
+    Ok(repository
+        .accounts()
+        .await?
+        .into_iter()
+        .map(|account| (account.name, account.currency))
+        .collect())
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 // Operation Functions
 ////
 
-// Import a list of transactions.
-async fn import(
+// Hex digest of the contents of the file, to identify it in the import
+// batch.
+fn hash_file(filename: &str) -> anyhow::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(filename)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// Import a list of transactions. Transactions which were already imported
+// are skipped, and transactions which may duplicate one that's already
+// recorded are only imported if import_flagged is set. The closing balance
+// of a statement is recorded as a balance snapshot, in the same import batch.
+async fn import<R: Repository>(
     filename: &str,
+    format: Format,
+    csv: &CsvParser,
+    account: &Option<String>,
     transaction_type: TransactionType,
-    db: &DatabaseConnection,
+    import_flagged: bool,
+    repository: &R,
 ) -> anyhow::Result<()> {
-    let records = read_transactions(filename, db, transaction_type).await?;
-    for record in &records {
-        transaction::create(db, record.clone().try_into()?).await?;
+    let (records, snapshots) = read_transactions(
+        filename,
+        format,
+        csv,
+        account,
+        repository,
+        transaction_type,
+    )
+    .await?;
+
+    // Report every invalid record, rather than only the first one. Nothing is
+    // created unless every record is valid.
+    let context = repository.validation_context().await?;
+    let mut transactions = Vec::new();
+    let mut errors = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        let result: Result<NewTransaction, ValidationError> =
+            record.clone().try_into();
+        match result.and_then(|t| context.validate(&t).map(|_| t)) {
+            Ok(transaction) => transactions.push(transaction),
+            Err(e) => errors.push(format!("Record {}: {}", index + 1, e)),
+        }
+    }
+    if !errors.is_empty() {
+        anyhow::bail!(errors.join("\n"));
+    }
+
+    let batch = NewImportBatch {
+        filename: filename.to_string(),
+        hash: hash_file(filename)?,
+        transaction_type: transaction_type.into(),
+    };
+    let matches = repository.match_imports(&batch, &transactions).await?;
+    let mut imported = Vec::new();
+    let mut new_transactions = Vec::new();
+    let mut skipped = 0;
+    let mut flagged = Vec::new();
+    for (index, ((record, transaction), found)) in records
+        .into_iter()
+        .zip(transactions)
+        .zip(matches)
+        .enumerate()
+    {
+        match found {
+            ImportMatch::Duplicate { .. } => skipped += 1,
+            ImportMatch::Possible { candidates } if !import_flagged => {
+                flagged.push(format!(
+                    "Record {} ({}, {}, {}) may duplicate transaction {}",
+                    index + 1,
+                    record.summary,
+                    record.date.format("%d %b %Y"),
+                    record.amount,
+                    candidates
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+            }
+            _ => {
+                imported.push(record);
+                new_transactions.push(transaction);
+            }
+        }
+    }
+
+    // Snapshots recorded by an earlier import of the statement are kept.
+    let existing = repository.balance_snapshots().await?;
+    let snapshots = snapshots
+        .into_iter()
+        .filter(|snapshot| {
+            !existing.iter().any(|s| {
+                s.account == snapshot.account && s.date == snapshot.date
+            })
+        })
+        .collect::<Vec<NewBalanceSnapshot>>();
+
+    if new_transactions.is_empty() && snapshots.is_empty() {
+        println!("Nothing to import");
+    } else {
+        let (batch, snapshots) = repository
+            .import_transactions(batch, new_transactions, snapshots)
+            .await?;
+        let currencies = account_currencies(repository).await?;
+        if !imported.is_empty() {
+            table::print(&imported);
+        }
+        for snapshot in snapshots {
+            println!(
+                "Recorded balance snapshot {} of {} for {}",
+                snapshot.id,
+                Money::new(
+                    snapshot.amount,
+                    currencies
+                        .get(&snapshot.account)
+                        .copied()
+                        .unwrap_or_default()
+                ),
+                snapshot.account
+            );
+        }
+        println!("Created import batch {}", batch.id);
+    }
+    if skipped > 0 {
+        println!(
+            "Skipped {} transactions which were already imported",
+            skipped
+        );
     }
+    if !flagged.is_empty() {
+        println!(
+            "Flagged {} transactions for review, which were not imported:",
+            flagged.len()
+        );
+        for line in flagged {
+            println!("    {}", line);
+        }
+    }
+    Ok(())
+}
 
+// List the transactions of the given type.
+async fn list<R: Repository>(
+    transaction_type: TransactionType,
+    budget: Option<i32>,
+    account: &Option<String>,
+    line_item: &Option<String>,
+    payee: &Option<String>,
+    repository: &R,
+) -> anyhow::Result<()> {
+    let payee = match payee {
+        Some(name) => Some(
+            repository
+                .payees()
+                .await?
+                .into_iter()
+                .find(|payee| &payee.name == name)
+                .ok_or_else(|| Error::not_found("payee", name))?
+                .id,
+        ),
+        None => None,
+    };
+    let filter = TransactionFilter {
+        transaction_type: Some(transaction_type.into()),
+        periodic_budget: budget,
+        account: account.clone(),
+        line_item: line_item.clone(),
+        payee,
+        ..Default::default()
+    };
+    let currencies = account_currencies(repository).await?;
+    let records = repository
+        .transactions(&filter)
+        .await?
+        .into_iter()
+        .map(|transaction| {
+            TransactionListRecord::new(transaction, &currencies)
+        })
+        .collect::<Vec<TransactionListRecord>>();
     table::print(&records);
     Ok(())
 }
 
-async fn delete_all_planned(
+// Show one transaction, given the id of any of its legs.
+async fn show<R: Repository>(id: i32, repository: &R) -> anyhow::Result<()> {
+    let currencies = account_currencies(repository).await?;
+    let transaction = TransactionListRecord::new(
+        repository.transaction(id).await?,
+        &currencies,
+    );
+    table::print(&[transaction]);
+    Ok(())
+}
+
+#[derive(Fields, FieldNames)]
+struct SuggestionRecord {
+    #[fields(rename = "Key")]
+    key: usize,
+    #[fields(rename = "Line Item")]
+    line_item: String,
+    #[fields(rename = "Confidence")]
+    confidence: String,
+}
+
+// How many suggestions are offered for each transaction.
+const SUGGESTIONS_OFFERED: usize = 3;
+
+fn print_suggestions(suggestions: &[models::LineItemSuggestion]) {
+    let records = suggestions
+        .iter()
+        .take(SUGGESTIONS_OFFERED)
+        .enumerate()
+        .map(|(index, suggestion)| SuggestionRecord {
+            key: index + 1,
+            line_item: suggestion.line_item.clone(),
+            confidence: format!("{:.0}%", suggestion.confidence * 100.0),
+        })
+        .collect::<Vec<SuggestionRecord>>();
+    table::print(&records);
+}
+
+// Show the suggested line items for a transaction.
+async fn suggest<R: Repository>(
+    id: i32,
+    repository: &R,
+) -> anyhow::Result<()> {
+    let suggestions = suggestion::suggest(repository, id).await?;
+    if suggestions.is_empty() {
+        println!("No suggestions for transaction {}", id);
+    } else {
+        print_suggestions(&suggestions);
+    }
+    Ok(())
+}
+
+// Offer the suggestions for each uncategorized transaction in turn. A key
+// accepts a suggestion, any other text is taken as the name of a line item,
+// an empty line skips the transaction, and "q" stops.
+async fn categorize<R: Repository>(repository: &R) -> anyhow::Result<()> {
+    let stdin = io::stdin();
+    let currencies = account_currencies(repository).await?;
+    for (transaction, suggestions) in
+        suggestion::suggest_all(repository).await?
+    {
+        let id = transaction.id;
+        table::print(&[TransactionListRecord::new(transaction, &currencies)]);
+        let offered = suggestions.len().min(SUGGESTIONS_OFFERED);
+        if offered > 0 {
+            print_suggestions(&suggestions);
+            print!(
+                "Line item (1-{}, or a name; Enter skips, q quits): ",
+                offered
+            );
+        } else {
+            print!("Line item (a name; Enter skips, q quits): ");
+        }
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        stdin.read_line(&mut input)?;
+        let line_item = match input.trim() {
+            "" => continue,
+            "q" => break,
+            key => match key.parse::<usize>() {
+                Ok(key) if (1..=offered).contains(&key) => {
+                    suggestions[key - 1].line_item.clone()
+                }
+                _ => key.to_string(),
+            },
+        };
+        suggestion::accept(repository, id, &line_item).await?;
+        println!("Categorized transaction {} as {}\n", id, line_item);
+    }
+    Ok(())
+}
+
+// Delete one transaction, along with every leg of its series.
+async fn delete<R: Repository>(id: i32, repository: &R) -> anyhow::Result<()> {
+    repository.delete_transaction(id).await?;
+    println!("Deleted transaction {}", id);
+    Ok(())
+}
+
+// Delete every transaction of the type which refers to the budget.
+async fn delete_all(
     budget: i32,
+    transaction_type: TransactionType,
     db: &DatabaseConnection,
 ) -> anyhow::Result<()> {
-    let transactions = PlannedTransactions::find()
-        .filter(planned_transactions::Column::PeriodicBudget.eq(budget))
-        .all(db)
-        .await?
-        .iter()
-        .map(|transaction| transaction.id)
-        .collect::<Vec<i32>>();
-    transactions::Entity::delete_many()
-        .filter(transactions::Column::Id.is_in(transactions))
-        .exec(db)
-        .await?;
+    let count =
+        transaction::delete_all(db, &transaction_type.into(), budget).await?;
+    println!("Deleted {} transactions", count);
     Ok(())
 }
 
@@ -199,11 +706,88 @@ async fn delete_all_planned(
 
 #[derive(Subcommand)]
 pub(crate) enum Verb {
-    /// Import a set of transactions from a CSV file.
+    /// Import a set of transactions from a CSV file or a bank statement.
+    /// Transactions which were already imported are skipped.
     Import {
         /// The file to import transactions from.
         #[clap(value_parser)]
         filename: String,
+
+        /// The format of the file.
+        #[arg(value_enum, default_value_t = Format::Budget)]
+        #[clap(short, long)]
+        format: Format,
+
+        /// The account of a bank statement. Required for every format but
+        /// budget.
+        #[clap(short, long, value_parser)]
+        account: Option<String>,
+
+        /// The column of the date in a CSV statement, counting from zero.
+        #[clap(long, value_parser, default_value_t = 0)]
+        date_column: usize,
+
+        /// The column of the description in a CSV statement.
+        #[clap(long, value_parser, default_value_t = 1)]
+        description_column: usize,
+
+        /// The column of the amount in a CSV statement.
+        #[clap(long, value_parser, default_value_t = 2)]
+        amount_column: usize,
+
+        /// The CSV statement has no header row.
+        #[clap(long)]
+        no_headers: bool,
+
+        /// Also import transactions flagged as possible duplicates, i.e.
+        /// recorded transactions of the same amount within a few days.
+        #[clap(long)]
+        import_flagged: bool,
+    },
+
+    /// List transactions.
+    List {
+        /// Only list transactions in this periodic budget.
+        #[clap(short, long, value_parser)]
+        budget: Option<i32>,
+
+        /// Only list transactions on this account.
+        #[clap(short, long, value_parser)]
+        account: Option<String>,
+
+        /// Only list transactions allocated to this line item.
+        #[clap(short, long, value_parser)]
+        line_item: Option<String>,
+
+        /// Only list transactions with this payee.
+        #[clap(short, long, value_parser)]
+        payee: Option<String>,
+    },
+
+    /// Show a transaction, given the id of any of its legs.
+    Show {
+        /// The transaction ID
+        #[clap(value_parser)]
+        id: i32,
+    },
+
+    /// Suggest line items for a real transaction, learned from the
+    /// transactions that are already categorized.
+    Suggest {
+        /// The transaction ID
+        #[clap(value_parser)]
+        id: i32,
+    },
+
+    /// Step through the uncategorized real transactions, accepting a
+    /// suggested line item for each with a single key.
+    Categorize,
+
+    /// Delete a transaction, and every leg of its series.
+    Delete {
+        /// The transaction ID
+        #[clap(value_parser)]
+        id: i32,
     },
 
     /// Delete all transactions which reference a budget.
@@ -219,14 +803,63 @@ pub(crate) async fn op(
     transaction_type: TransactionType,
     db: &DatabaseConnection,
 ) -> anyhow::Result<()> {
+    let repository = DatabaseRepository::new(db.clone());
     match verb {
-        Verb::Import { filename } => {
-            import(filename, transaction_type, db).await
+        Verb::Import {
+            filename,
+            format,
+            account,
+            date_column,
+            description_column,
+            amount_column,
+            no_headers,
+            import_flagged,
+        } => {
+            let csv = CsvParser {
+                date: *date_column,
+                description: *description_column,
+                amount: *amount_column,
+                has_headers: !*no_headers,
+            };
+            import(
+                filename,
+                *format,
+                &csv,
+                account,
+                transaction_type,
+                *import_flagged,
+                &repository,
+            )
+            .await
         }
-        Verb::DeleteAll { budget } => match transaction_type {
-            TransactionType::Planned => delete_all_planned(*budget, db).await,
-            TransactionType::Real => todo!(),
+        Verb::List {
+            budget,
+            account,
+            line_item,
+            payee,
+        } => {
+            list(
+                transaction_type,
+                *budget,
+                account,
+                line_item,
+                payee,
+                &repository,
+            )
+            .await
+        }
+        Verb::Show { id } => show(*id, &repository).await,
+        Verb::Suggest { id } => suggest(*id, &repository).await,
+        Verb::Categorize => match transaction_type {
+            TransactionType::Real => categorize(&repository).await,
+            TransactionType::Planned => {
+                anyhow::bail!("Only real transactions can be categorized")
+            }
         },
+        Verb::Delete { id } => delete(*id, &repository).await,
+        Verb::DeleteAll { budget } => {
+            delete_all(*budget, transaction_type, db).await
+        }
     }
 }
 
@@ -7,7 +7,7 @@
 //
 // CREATED:         09/18/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,17 +24,22 @@
 // limitations under the License.
 ////
 
-use budget_backend_lib::secret::SecretManager;
+use budget_backend_lib::secret::{SecretFormat, SecretManager};
 use clap::Parser;
-use std::env;
 use sqlx::PgPool;
+use std::env;
 
 #[derive(Parser, Debug)]
 #[clap(author, version)]
 struct Args {
-    /// Path to a JSON file containing database credentials
+    /// Path to the file or directory containing database credentials
     #[clap(short, long, value_parser)]
-    secret_file: String,
+    secret_file: Option<String>,
+
+    /// Where to read database credentials from: json, yaml, toml, env,
+    /// directory (one file per key) or pgpass
+    #[clap(long, value_parser, default_value = "json")]
+    secret_format: SecretFormat,
 }
 
 #[tokio::main]
@@ -42,14 +47,13 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     let url_template =
         env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let secret_manager = SecretManager::new(args.secret_file);
+    let source = args.secret_format.source(args.secret_file)?;
+    let secret_manager = SecretManager::new(source);
     let url = secret_manager.with_url(url_template.parse()?)?;
 
     // Run database migrations
     let pool = PgPool::connect(&url).await?;
-    sqlx::migrate!("../migrations")
-        .run(&pool)
-        .await?;
+    sqlx::migrate!("../migrations").run(&pool).await?;
 
     Ok(())
 }
@@ -7,7 +7,7 @@
 //
 // CREATED:         07/12/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,6 +24,10 @@
 // limitations under the License.
 ////
 
+use std::cell::OnceCell;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
 pub trait Calculation {
     type Input;
     type Result;
@@ -32,3 +36,372 @@ pub trait Calculation {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+// CalculationExt
+//  Combinators for building a pipeline of calculations, so that a report can
+//  be produced in a single pass over a stream of transactions. For example,
+//  spending per line item per month:
+//
+//      BurnUpTotal::default()
+//          .map_input(|row: &Row| row.amount)
+//          .group_by(|row| (row.line_item.clone(), row.month))
+////
+
+pub trait CalculationExt: Calculation + Sized {
+    // Apply every input from an iterator, in order.
+    fn apply_all<I>(&mut self, inputs: I)
+    where
+        I: IntoIterator<Item = Self::Input>,
+    {
+        for input in inputs {
+            self.apply(input);
+        }
+    }
+
+    // Transform each input before it's applied to the calculation.
+    fn map_input<I, F>(self, function: F) -> MapInput<Self, I, F>
+    where
+        F: FnMut(I) -> Self::Input,
+    {
+        MapInput {
+            inner: self,
+            function,
+            input: PhantomData,
+        }
+    }
+
+    // Only apply inputs which satisfy the predicate.
+    fn filter<F>(self, predicate: F) -> Filter<Self, F>
+    where
+        F: FnMut(&Self::Input) -> bool,
+    {
+        Filter {
+            inner: self,
+            predicate,
+        }
+    }
+
+    // Keep a separate copy of the calculation for each key. Every group
+    // starts from a clone of this calculation.
+    fn group_by<K, F>(self, key: F) -> GroupBy<Self, K, F>
+    where
+        K: Ord,
+        F: FnMut(&Self::Input) -> K,
+    {
+        GroupBy {
+            prototype: self,
+            key,
+            groups: BTreeMap::new(),
+            results: OnceCell::new(),
+        }
+    }
+
+    // Apply each input to this calculation and another one, in one pass.
+    fn fan_out<C>(self, other: C) -> FanOut<Self, C>
+    where
+        C: Calculation<Input = Self::Input>,
+    {
+        FanOut {
+            first: self,
+            second: other,
+            results: OnceCell::new(),
+        }
+    }
+
+    // Record the result of the calculation at the end of each period. Inputs
+    // must be applied in order of period, e.g. by date.
+    fn snapshot<K, F>(self, period: F) -> Snapshot<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Input) -> K,
+    {
+        Snapshot {
+            inner: self,
+            period,
+            current: None,
+            completed: Vec::new(),
+            snapshots: OnceCell::new(),
+        }
+    }
+}
+
+impl<C: Calculation> CalculationExt for C {}
+
+///////////////////////////////////////////////////////////////////////////////
+// MapInput
+////
+
+#[derive(Clone)]
+pub struct MapInput<C, I, F> {
+    inner: C,
+    function: F,
+    input: PhantomData<fn(I)>,
+}
+
+impl<C, I, F> Calculation for MapInput<C, I, F>
+where
+    C: Calculation,
+    F: FnMut(I) -> C::Input,
+{
+    type Input = I;
+    type Result = C::Result;
+    fn apply(&mut self, input: Self::Input) {
+        self.inner.apply((self.function)(input));
+    }
+
+    fn calculate(&self) -> &Self::Result {
+        self.inner.calculate()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Filter
+////
+
+#[derive(Clone)]
+pub struct Filter<C, F> {
+    inner: C,
+    predicate: F,
+}
+
+impl<C, F> Calculation for Filter<C, F>
+where
+    C: Calculation,
+    F: FnMut(&C::Input) -> bool,
+{
+    type Input = C::Input;
+    type Result = C::Result;
+    fn apply(&mut self, input: Self::Input) {
+        if (self.predicate)(&input) {
+            self.inner.apply(input);
+        }
+    }
+
+    fn calculate(&self) -> &Self::Result {
+        self.inner.calculate()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// GroupBy
+//  Like the other combinators which own several calculations, the result is
+//  only collected from the inner calculations when it's asked for, and kept
+//  until the next input is applied.
+////
+
+#[derive(Clone)]
+pub struct GroupBy<C: Calculation, K, F> {
+    prototype: C,
+    key: F,
+    groups: BTreeMap<K, C>,
+    results: OnceCell<BTreeMap<K, C::Result>>,
+}
+
+impl<C, K, F> GroupBy<C, K, F>
+where
+    C: Calculation,
+{
+    // The calculation for each group, e.g. to call methods on it other than
+    // calculate().
+    pub fn groups(&self) -> &BTreeMap<K, C> {
+        &self.groups
+    }
+}
+
+impl<C, K, F> Calculation for GroupBy<C, K, F>
+where
+    C: Calculation + Clone,
+    C::Result: Clone,
+    K: Ord + Clone,
+    F: FnMut(&C::Input) -> K,
+{
+    type Input = C::Input;
+    type Result = BTreeMap<K, C::Result>;
+    fn apply(&mut self, input: Self::Input) {
+        let key = (self.key)(&input);
+        let group = self
+            .groups
+            .entry(key.clone())
+            .or_insert_with(|| self.prototype.clone());
+        group.apply(input);
+        self.results.take();
+    }
+
+    fn calculate(&self) -> &Self::Result {
+        self.results.get_or_init(|| {
+            self.groups
+                .iter()
+                .map(|(key, group)| (key.clone(), group.calculate().clone()))
+                .collect()
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FanOut
+////
+
+#[derive(Clone)]
+pub struct FanOut<A: Calculation, B: Calculation> {
+    first: A,
+    second: B,
+    results: OnceCell<(A::Result, B::Result)>,
+}
+
+impl<A, B> FanOut<A, B>
+where
+    A: Calculation,
+    B: Calculation,
+{
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B> Calculation for FanOut<A, B>
+where
+    A: Calculation,
+    A::Input: Clone,
+    A::Result: Clone,
+    B: Calculation<Input = A::Input>,
+    B::Result: Clone,
+{
+    type Input = A::Input;
+    type Result = (A::Result, B::Result);
+    fn apply(&mut self, input: Self::Input) {
+        self.first.apply(input.clone());
+        self.second.apply(input);
+        self.results.take();
+    }
+
+    fn calculate(&self) -> &Self::Result {
+        self.results.get_or_init(|| {
+            (
+                self.first.calculate().clone(),
+                self.second.calculate().clone(),
+            )
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Snapshot
+//  The result of the calculation is cumulative across periods. A period's
+//  snapshot is taken when the first input of the next period arrives, and the
+//  last snapshot is always the current result.
+////
+
+#[derive(Clone)]
+pub struct Snapshot<C: Calculation, K, F> {
+    inner: C,
+    period: F,
+    current: Option<K>,
+    completed: Vec<(K, C::Result)>,
+    snapshots: OnceCell<Vec<(K, C::Result)>>,
+}
+
+impl<C, K, F> Calculation for Snapshot<C, K, F>
+where
+    C: Calculation,
+    C::Result: Clone,
+    K: PartialEq + Clone,
+    F: FnMut(&C::Input) -> K,
+{
+    type Input = C::Input;
+    type Result = Vec<(K, C::Result)>;
+    fn apply(&mut self, input: Self::Input) {
+        let period = (self.period)(&input);
+        if self.current.as_ref() != Some(&period) {
+            if let Some(last) = self.current.replace(period) {
+                let result = self.inner.calculate().clone();
+                self.completed.push((last, result));
+            }
+        }
+        self.inner.apply(input);
+        self.snapshots.take();
+    }
+
+    fn calculate(&self) -> &Self::Result {
+        self.snapshots.get_or_init(|| {
+            let current = self.current.iter().map(|period| {
+                (period.clone(), self.inner.calculate().clone())
+            });
+            self.completed.iter().cloned().chain(current).collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Sum(i64);
+
+    impl Calculation for Sum {
+        type Input = i64;
+        type Result = i64;
+        fn apply(&mut self, input: Self::Input) {
+            self.0 += input;
+        }
+
+        fn calculate(&self) -> &Self::Result {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn map_input_and_filter() {
+        let mut sum = Sum::default()
+            .filter(|amount| *amount > 0)
+            .map_input(|text: &str| text.parse::<i64>().unwrap());
+        sum.apply_all(["3", "-4", "5"]);
+        assert_eq!(&8, sum.calculate());
+    }
+
+    #[test]
+    fn group_by_refreshes_after_apply() {
+        let mut groups = Sum::default().group_by(|amount| amount % 2 == 0);
+        groups.apply_all([1, 2, 3]);
+        let expected = BTreeMap::from([(false, 4), (true, 2)]);
+        assert_eq!(&expected, groups.calculate());
+        assert_eq!(&4, groups.groups()[&false].calculate());
+
+        // The cached results must not outlive the next input.
+        groups.apply(4);
+        let expected = BTreeMap::from([(false, 4), (true, 6)]);
+        assert_eq!(&expected, groups.calculate());
+    }
+
+    #[test]
+    fn fan_out_applies_to_both() {
+        let mut both = Sum::default()
+            .fan_out(Sum::default().filter(|amount| *amount < 0));
+        both.apply_all([5, -2]);
+        assert_eq!(&(3, -2), both.calculate());
+
+        both.apply(-1);
+        assert_eq!(&(2, -3), both.calculate());
+        assert_eq!(&2, both.first().calculate());
+        assert_eq!(&-3, both.second().calculate());
+    }
+
+    #[test]
+    fn snapshot_is_cumulative_per_period() {
+        let empty = Sum::default().snapshot(|amount| amount / 10);
+        assert!(empty.calculate().is_empty());
+
+        let mut snapshots = Sum::default().snapshot(|amount| amount / 10);
+        snapshots.apply_all([1, 2, 15, 31]);
+        assert_eq!(&vec![(0, 3), (1, 18), (3, 49)], snapshots.calculate());
+
+        // The current period's snapshot follows the latest input.
+        snapshots.apply(32);
+        assert_eq!(&vec![(0, 3), (1, 18), (3, 81)], snapshots.calculate());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
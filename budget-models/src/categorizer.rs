@@ -0,0 +1,357 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            categorizer.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Line item suggestions learned from categorized
+//                  transactions.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{
+    normalize_description, LineItemSuggestion, Transaction, TransactionData,
+    TransactionTypedMetadata,
+};
+
+// The number of most similar transactions which vote on a suggestion.
+const NEIGHBOURS: usize = 5;
+
+// How much each kind of similarity contributes. These sum to one.
+const DESCRIPTION_WEIGHT: f64 = 0.6;
+const AMOUNT_WEIGHT: f64 = 0.25;
+const ACCOUNT_WEIGHT: f64 = 0.15;
+
+struct Example {
+    tokens: HashSet<String>,
+    account: String,
+    amount: i64,
+    line_item: String,
+}
+
+// The words of a description. Numbers are left out, since they're usually
+// store or reference numbers, which differ between otherwise identical
+// transactions.
+fn tokens(summary: &str) -> HashSet<String> {
+    normalize_description(summary)
+        .split(' ')
+        .filter(|token| !token.chars().all(|c| c.is_ascii_digit()))
+        .map(|token| token.to_string())
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Categorizer
+// A nearest-neighbour model over the legs of categorized transactions. The
+// similarity of two legs is a weighted sum of the overlap of the words in
+// their descriptions, the ratio of their amounts, and whether they're on the
+// same account. Legs whose descriptions have no words in common are never
+// considered similar.
+////
+
+#[derive(Default)]
+pub struct Categorizer {
+    examples: Vec<Example>,
+}
+
+impl Categorizer {
+    // Learn from every real transaction that's allocated to exactly one line
+    // item. Planned and split transactions are ignored.
+    pub fn train<'a, I>(transactions: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Transaction>,
+    {
+        let mut categorizer = Self::default();
+        for transaction in transactions {
+            let allocations = match &transaction.metadata {
+                TransactionTypedMetadata::Real(Some(metadata)) => {
+                    &metadata.allocations
+                }
+                _ => continue,
+            };
+            if let [allocation] = allocations.as_slice() {
+                for leg in transaction.series.legs() {
+                    categorizer.learn(leg, &allocation.line_item);
+                }
+            }
+        }
+        categorizer
+    }
+
+    pub fn learn(&mut self, leg: &TransactionData, line_item: &str) {
+        self.examples.push(Example {
+            tokens: tokens(&leg.summary),
+            account: leg.account.clone(),
+            amount: leg.amount,
+            line_item: line_item.to_string(),
+        });
+    }
+
+    fn similarity(
+        example: &Example,
+        tokens: &HashSet<String>,
+        leg: &TransactionData,
+    ) -> f64 {
+        let common = example.tokens.intersection(tokens).count();
+        if common == 0 {
+            return 0.0;
+        }
+        let description =
+            common as f64 / example.tokens.union(tokens).count() as f64;
+
+        // Amounts of different signs are unrelated.
+        let (a, b) = (example.amount, leg.amount);
+        let amount = if a == b {
+            1.0
+        } else if (a < 0) == (b < 0) {
+            a.abs().min(b.abs()) as f64 / a.abs().max(b.abs()) as f64
+        } else {
+            0.0
+        };
+
+        let account = if example.account == leg.account {
+            1.0
+        } else {
+            0.0
+        };
+        DESCRIPTION_WEIGHT * description
+            + AMOUNT_WEIGHT * amount
+            + ACCOUNT_WEIGHT * account
+    }
+
+    // Rank the line items that the transaction may belong to. The nearest
+    // neighbours of the transaction each vote for their line item with their
+    // similarity. The confidence of a line item is its share of the vote,
+    // scaled by the similarity of its nearest neighbour, so that a unanimous
+    // vote of poor matches is still reported with low confidence.
+    pub fn suggest(
+        &self,
+        transaction: &Transaction,
+    ) -> Vec<LineItemSuggestion> {
+        let legs = transaction
+            .series
+            .legs()
+            .into_iter()
+            .map(|leg| (tokens(&leg.summary), leg))
+            .collect::<Vec<(HashSet<String>, &TransactionData)>>();
+        let mut neighbours = self
+            .examples
+            .iter()
+            .map(|example| {
+                let similarity = legs
+                    .iter()
+                    .map(|(tokens, leg)| {
+                        Self::similarity(example, tokens, leg)
+                    })
+                    .fold(0.0, f64::max);
+                (example, similarity)
+            })
+            .filter(|(_, similarity)| *similarity > 0.0)
+            .collect::<Vec<(&Example, f64)>>();
+        neighbours.sort_by(|a, b| b.1.total_cmp(&a.1));
+        neighbours.truncate(NEIGHBOURS);
+
+        let total: f64 =
+            neighbours.iter().map(|(_, similarity)| similarity).sum();
+        let mut votes: HashMap<&str, (f64, f64)> = HashMap::new();
+        for (example, similarity) in &neighbours {
+            let vote = votes.entry(&example.line_item).or_insert((0.0, 0.0));
+            vote.0 += similarity;
+            vote.1 = vote.1.max(*similarity);
+        }
+
+        let mut suggestions = votes
+            .into_iter()
+            .map(|(line_item, (score, nearest))| LineItemSuggestion {
+                line_item: line_item.to_string(),
+                confidence: score / total * nearest,
+            })
+            .collect::<Vec<LineItemSuggestion>>();
+        suggestions.sort_by(|a, b| {
+            b.confidence
+                .total_cmp(&a.confidence)
+                .then_with(|| a.line_item.cmp(&b.line_item))
+        });
+        suggestions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Allocation, TransactionMetadata, TransactionSeries};
+
+    fn leg(summary: &str, account: &str, amount: i64) -> TransactionData {
+        TransactionData {
+            summary: summary.to_string(),
+            account: account.to_string(),
+            amount,
+            ..Default::default()
+        }
+    }
+
+    fn transaction(leg: TransactionData) -> Transaction {
+        Transaction {
+            id: 1,
+            metadata: TransactionTypedMetadata::Real(None),
+            series: TransactionSeries::Single(leg),
+        }
+    }
+
+    fn categorized(
+        metadata: TransactionTypedMetadata,
+        summary: &str,
+    ) -> Transaction {
+        Transaction {
+            metadata,
+            ..transaction(leg(summary, "Checking", -500))
+        }
+    }
+
+    fn allocated(line_items: &[&str]) -> TransactionMetadata {
+        TransactionMetadata {
+            periodic_budget: Some(1),
+            one_time_budget: None,
+            allocations: line_items
+                .iter()
+                .map(|line_item| Allocation {
+                    line_item: line_item.to_string(),
+                    amount: -500 / line_items.len() as i64,
+                })
+                .collect(),
+        }
+    }
+
+    fn ranking(suggestions: &[LineItemSuggestion]) -> Vec<&str> {
+        suggestions.iter().map(|s| s.line_item.as_str()).collect()
+    }
+
+    #[test]
+    fn identical_legs_are_certain() {
+        let mut categorizer = Categorizer::default();
+        categorizer.learn(&leg("GROCER #123", "Checking", -4512), "Groceries");
+        let suggestions = categorizer.suggest(&transaction(leg(
+            "Grocer 456",
+            "Checking",
+            -4512,
+        )));
+        assert_eq!(
+            vec![LineItemSuggestion {
+                line_item: "Groceries".to_string(),
+                confidence: 1.0,
+            }],
+            suggestions
+        );
+    }
+
+    #[test]
+    fn nearest_neighbours_outrank_more_votes() {
+        let mut categorizer = Categorizer::default();
+        categorizer
+            .learn(&leg("Corner Market", "Checking", -500), "Groceries");
+        categorizer
+            .learn(&leg("Corner Market", "Checking", -1000), "Groceries");
+        categorizer.learn(&leg("Corner Cafe", "Credit", -500), "Dining");
+        categorizer.learn(&leg("Hardware", "Checking", -500), "Household");
+        let suggestions = categorizer.suggest(&transaction(leg(
+            "Corner Cafe",
+            "Checking",
+            -500,
+        )));
+        assert_eq!(vec!["Dining", "Groceries"], ranking(&suggestions));
+
+        // Dining's one neighbour is 0.85 similar, and Groceries' are 0.6 and
+        // 0.475 similar.
+        let total = 0.85 + 0.6 + 0.475;
+        let expected = [0.85 / total * 0.85, (0.6 + 0.475) / total * 0.6];
+        for (suggestion, expected) in suggestions.iter().zip(expected) {
+            assert!((suggestion.confidence - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn poor_matches_have_low_confidence() {
+        let mut categorizer = Categorizer::default();
+        categorizer
+            .learn(&leg("Corner Market", "Checking", -500), "Groceries");
+        let suggestions = categorizer.suggest(&transaction(leg(
+            "Corner Store",
+            "Savings",
+            500,
+        )));
+        assert_eq!(vec!["Groceries"], ranking(&suggestions));
+        assert!((suggestions[0].confidence - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn only_the_nearest_neighbours_vote() {
+        let mut categorizer = Categorizer::default();
+        for _ in 0..NEIGHBOURS {
+            categorizer.learn(&leg("Cafe", "Checking", -500), "Dining");
+        }
+        categorizer.learn(&leg("Cafe", "Checking", -400), "Coffee");
+        let suggestions =
+            categorizer.suggest(&transaction(leg("Cafe", "Checking", -500)));
+        assert_eq!(vec!["Dining"], ranking(&suggestions));
+    }
+
+    #[test]
+    fn ties_are_ranked_by_line_item() {
+        let mut categorizer = Categorizer::default();
+        categorizer.learn(&leg("Cafe", "Checking", -500), "Dining");
+        categorizer.learn(&leg("Cafe", "Checking", -500), "Coffee");
+        let suggestions =
+            categorizer.suggest(&transaction(leg("Cafe", "Checking", -500)));
+        assert_eq!(vec!["Coffee", "Dining"], ranking(&suggestions));
+        assert_eq!(suggestions[0].confidence, suggestions[1].confidence);
+
+        let unrelated = transaction(leg("Hardware", "Checking", -500));
+        assert!(categorizer.suggest(&unrelated).is_empty());
+    }
+
+    #[test]
+    fn training_ignores_planned_and_split_transactions() {
+        let transactions = vec![
+            categorized(
+                TransactionTypedMetadata::Real(Some(allocated(&["Dining"]))),
+                "Cafe",
+            ),
+            categorized(
+                TransactionTypedMetadata::Planned(allocated(&["Coffee"])),
+                "Cafe",
+            ),
+            categorized(
+                TransactionTypedMetadata::Real(Some(allocated(&[
+                    "Groceries",
+                    "Household",
+                ]))),
+                "Cafe",
+            ),
+            categorized(TransactionTypedMetadata::Real(None), "Cafe"),
+        ];
+        let categorizer = Categorizer::train(&transactions);
+        let suggestions =
+            categorizer.suggest(&transaction(leg("Cafe", "Checking", -500)));
+        assert_eq!(vec!["Dining"], ranking(&suggestions));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
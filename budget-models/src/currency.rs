@@ -0,0 +1,87 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            currency.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Currencies that money may be denominated in.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+///////////////////////////////////////////////////////////////////////////////
+// Currency
+//  An ISO 4217 currency. Amounts of money are always stored in the minor unit
+//  of their currency (e.g. cents), so the number of decimal places is needed
+//  to interpret or display them.
+////
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    EnumIter,
+    EnumString,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Deserialize,
+    Serialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE", ascii_case_insensitive)]
+pub enum Currency {
+    #[default]
+    Usd,
+    Aud,
+    Cad,
+    Eur,
+    Gbp,
+    Jpy,
+    Mxn,
+}
+
+impl Currency {
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            Currency::Jpy => 0,
+            _ => 2,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Usd => "$",
+            Currency::Aud => "A$",
+            Currency::Cad => "CA$",
+            Currency::Eur => "\u{20ac}",
+            Currency::Gbp => "\u{a3}",
+            Currency::Jpy => "\u{a5}",
+            Currency::Mxn => "MX$",
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
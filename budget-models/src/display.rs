@@ -7,7 +7,7 @@
 //
 // CREATED:         11/12/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,10 +24,38 @@
 // limitations under the License.
 ////
 
+use crate::currency::Currency;
+use crate::models::Allocation;
+use crate::money::Money;
 use chrono::{DateTime, FixedOffset};
 
 pub fn date(value: &DateTime<FixedOffset>) -> String {
     value.format("%d %b %Y").to_string()
 }
 
+// A single line item is displayed by name. Split transactions display each
+// line item along with its share, e.g. "Groceries=$45.10; Gifts=$14.89".
+pub fn allocations(value: &[Allocation]) -> String {
+    allocations_in(value, Currency::default())
+}
+
+// Like allocations(), for the allocations of a transaction whose amount is
+// in the given currency.
+pub fn allocations_in(value: &[Allocation], currency: Currency) -> String {
+    match value {
+        [allocation] => allocation.line_item.clone(),
+        _ => value
+            .iter()
+            .map(|allocation| {
+                format!(
+                    "{}={}",
+                    &allocation.line_item,
+                    Money::new(allocation.amount, currency)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("; "),
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         04/10/2022
 //
-// LAST EDITED:     11/20/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,10 +24,12 @@
 // limitations under the License.
 ////
 
+pub mod currency;
 pub mod display;
 pub mod models;
 pub mod money;
 
+pub use currency::*;
 pub use models::*;
 pub use money::*;
 
@@ -37,6 +39,9 @@ extern crate cfg_if;
 cfg_if! {
     if #[cfg(feature = "business-logic")] {
         pub mod calculation;
+        pub mod categorizer;
+        pub mod normalization;
+        pub mod rules;
         pub mod total;
     }
 }
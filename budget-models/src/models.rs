@@ -7,7 +7,7 @@
 //
 // CREATED:         04/10/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -26,20 +26,42 @@
 
 mod account;
 mod account_type;
+mod balance_snapshot;
 mod categories;
+mod exchange_rate;
+mod import;
+mod import_batch;
 mod initial_balance;
+mod line_item;
+mod line_item_rule;
 mod one_time_budget;
+mod payee;
 mod periodic_budget;
+mod reconciliation;
+mod recurrence;
+mod suggestion;
 mod tag;
 mod transaction;
+mod validation;
 
 pub use account::*;
 pub use account_type::*;
+pub use balance_snapshot::*;
 pub use categories::*;
+pub use exchange_rate::*;
+pub use import::*;
+pub use import_batch::*;
 pub use initial_balance::*;
+pub use line_item::*;
+pub use line_item_rule::*;
 pub use one_time_budget::*;
+pub use payee::*;
 pub use periodic_budget::*;
+pub use reconciliation::*;
+pub use recurrence::*;
+pub use suggestion::*;
 pub use tag::*;
 pub use transaction::*;
+pub use validation::*;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,6 +25,7 @@
 ////
 
 use super::AccountType;
+use crate::currency::Currency;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +33,7 @@ use serde::{Deserialize, Serialize};
 pub struct Account {
     pub name: String,
     pub account_type: AccountType,
+    pub currency: Currency,
 
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub date_opened: DateTime<Utc>,
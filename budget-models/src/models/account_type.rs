@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -26,12 +26,52 @@
 
 use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum AccountClass {
+    Asset,
+    Liability,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum AccountType {
     Checking,
     Saving,
     Credit,
     Loan,
+    Investment,
+    Cash,
+    Retirement,
+    Hsa,
+    Mortgage,
+    Other,
+}
+
+impl AccountType {
+    // The balance of an asset account is what the account is worth, and the
+    // balance of a liability account is what is owed on it, as it would
+    // appear on a statement.
+    pub fn class(&self) -> AccountClass {
+        match self {
+            AccountType::Checking
+            | AccountType::Saving
+            | AccountType::Investment
+            | AccountType::Cash
+            | AccountType::Retirement
+            | AccountType::Hsa
+            | AccountType::Other => AccountClass::Asset,
+            AccountType::Credit
+            | AccountType::Loan
+            | AccountType::Mortgage => AccountClass::Liability,
+        }
+    }
+
+    pub fn is_asset(&self) -> bool {
+        self.class() == AccountClass::Asset
+    }
+
+    pub fn is_liability(&self) -> bool {
+        self.class() == AccountClass::Liability
+    }
 }
 
 impl ToString for AccountType {
@@ -43,14 +83,22 @@ impl ToString for AccountType {
 #[derive(Debug)]
 pub struct ParseAccountTypeError;
 
+// Accepts both the names of the variants (e.g. "Checking") and the values of
+// the AccountType enum in the database (e.g. "checking").
 impl TryFrom<String> for AccountType {
     type Error = ParseAccountTypeError;
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match value.as_str() {
-            "Checking" => Ok(AccountType::Checking),
-            "Saving" => Ok(AccountType::Saving),
-            "Credit" => Ok(AccountType::Credit),
-            "Loan" => Ok(AccountType::Loan),
+        match value.to_lowercase().as_str() {
+            "checking" => Ok(AccountType::Checking),
+            "saving" => Ok(AccountType::Saving),
+            "credit" => Ok(AccountType::Credit),
+            "loan" => Ok(AccountType::Loan),
+            "investment" => Ok(AccountType::Investment),
+            "cash" => Ok(AccountType::Cash),
+            "retirement" => Ok(AccountType::Retirement),
+            "hsa" => Ok(AccountType::Hsa),
+            "mortgage" => Ok(AccountType::Mortgage),
+            "other" => Ok(AccountType::Other),
             _ => Err(ParseAccountTypeError),
         }
     }
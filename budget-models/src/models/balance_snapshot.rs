@@ -0,0 +1,53 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            balance_snapshot.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Balance of an account as of a particular date.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use chrono::{offset::Utc, DateTime};
+use serde::{Deserialize, Serialize};
+
+///////////////////////////////////////////////////////////////////////////////
+// BalanceSnapshot
+// The balance of an account as reported by the institution, e.g. from a
+// statement, as of a particular date.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BalanceSnapshot {
+    pub id: i32,
+    pub account: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub date: DateTime<Utc>,
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct NewBalanceSnapshot {
+    pub account: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub date: DateTime<Utc>,
+    pub amount: i64,
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,11 +25,227 @@
 ////
 
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-#[derive(Serialize, Deserialize)]
+use crate::Money;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Category {
     pub name: String,
     pub parent: Option<String>,
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+// CategoryTree
+//  Categories form a forest, where each category may have one parent.
+////
+
+#[derive(Clone, Debug, Default)]
+pub struct CategoryTree {
+    categories: HashMap<String, Category>,
+    children: HashMap<String, Vec<String>>,
+}
+
+impl CategoryTree {
+    pub fn new(categories: Vec<Category>) -> Self {
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        for category in &categories {
+            if let Some(parent) = &category.parent {
+                children
+                    .entry(parent.clone())
+                    .or_default()
+                    .push(category.name.clone());
+            }
+        }
+        for names in children.values_mut() {
+            names.sort();
+        }
+
+        let categories = categories
+            .into_iter()
+            .map(|category| (category.name.clone(), category))
+            .collect();
+        CategoryTree {
+            categories,
+            children,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Category> {
+        self.categories.get(name)
+    }
+
+    // The categories which have no parent.
+    pub fn roots(&self) -> Vec<&Category> {
+        let mut roots = self
+            .categories
+            .values()
+            .filter(|category| category.parent.is_none())
+            .collect::<Vec<&Category>>();
+        roots.sort_by(|a, b| a.name.cmp(&b.name));
+        roots
+    }
+
+    pub fn children(&self, name: &str) -> Vec<&Category> {
+        self.children
+            .get(name)
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|name| self.categories.get(name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // The ancestors of the category, starting with its parent and ending with
+    // the root of its tree. The database should prevent cycles, but the walk
+    // stops if one is found.
+    pub fn ancestors(&self, name: &str) -> Vec<&Category> {
+        let mut ancestors = Vec::new();
+        let mut visited = HashSet::from([name]);
+        let mut current = self.categories.get(name);
+        while let Some(parent) = current.and_then(|c| c.parent.as_deref()) {
+            if !visited.insert(parent) {
+                break;
+            }
+            current = self.categories.get(parent);
+            if let Some(category) = current {
+                ancestors.push(category);
+            }
+        }
+        ancestors
+    }
+
+    // Every category below this one in the tree, breadth-first. The category
+    // itself is not included.
+    pub fn descendants(&self, name: &str) -> Vec<&Category> {
+        let mut descendants = Vec::new();
+        let mut visited = HashSet::from([name]);
+        let mut queue = VecDeque::from([name]);
+        while let Some(next) = queue.pop_front() {
+            for child in self.children(next) {
+                if visited.insert(&child.name) {
+                    descendants.push(child);
+                    queue.push_back(&child.name);
+                }
+            }
+        }
+        descendants
+    }
+
+    // Roll up totals, so that the total of each category is the sum of its
+    // own total and the totals of all of its descendants. Totals for names
+    // which are not in the tree are ignored.
+    pub fn roll_up(
+        &self,
+        totals: &HashMap<String, Money>,
+    ) -> HashMap<String, Money> {
+        self.categories
+            .keys()
+            .map(|name| {
+                let total = std::iter::once(name.as_str())
+                    .chain(
+                        self.descendants(name)
+                            .into_iter()
+                            .map(|category| category.name.as_str()),
+                    )
+                    .filter_map(|name| totals.get(name))
+                    .sum();
+                (name.clone(), total)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Currency;
+
+    fn category(name: &str, parent: Option<&str>) -> Category {
+        Category {
+            name: name.to_string(),
+            parent: parent.map(|parent| parent.to_string()),
+        }
+    }
+
+    // Housing -> {Utilities -> {Electric, Water}, Rent}, and Food.
+    fn tree() -> CategoryTree {
+        CategoryTree::new(vec![
+            category("Water", Some("Utilities")),
+            category("Food", None),
+            category("Utilities", Some("Housing")),
+            category("Rent", Some("Housing")),
+            category("Electric", Some("Utilities")),
+            category("Housing", None),
+        ])
+    }
+
+    fn names(categories: Vec<&Category>) -> Vec<&str> {
+        categories
+            .into_iter()
+            .map(|category| category.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn roots_and_children_are_sorted() {
+        let tree = tree();
+        assert_eq!(vec!["Food", "Housing"], names(tree.roots()));
+        assert_eq!(vec!["Rent", "Utilities"], names(tree.children("Housing")));
+        assert!(tree.children("Food").is_empty());
+        assert!(tree.children("Missing").is_empty());
+    }
+
+    #[test]
+    fn ancestors_end_at_the_root() {
+        let tree = tree();
+        assert_eq!(
+            vec!["Utilities", "Housing"],
+            names(tree.ancestors("Water"))
+        );
+        assert!(tree.ancestors("Housing").is_empty());
+    }
+
+    #[test]
+    fn descendants_are_breadth_first() {
+        let tree = tree();
+        assert_eq!(
+            vec!["Rent", "Utilities", "Electric", "Water"],
+            names(tree.descendants("Housing"))
+        );
+        assert!(tree.descendants("Water").is_empty());
+    }
+
+    #[test]
+    fn walks_stop_at_a_cycle() {
+        let tree = CategoryTree::new(vec![
+            category("A", Some("C")),
+            category("B", Some("A")),
+            category("C", Some("B")),
+        ]);
+        assert_eq!(vec!["C", "B"], names(tree.ancestors("A")));
+        assert_eq!(vec!["B", "C"], names(tree.descendants("A")));
+    }
+
+    #[test]
+    fn roll_up_includes_every_descendant() {
+        let usd = |amount: i64| Money::new(amount, Currency::Usd);
+        let totals = HashMap::from([
+            ("Electric".to_string(), usd(-6000)),
+            ("Water".to_string(), usd(-2500)),
+            ("Rent".to_string(), usd(-120000)),
+            ("Housing".to_string(), usd(-100)),
+            ("Unknown".to_string(), usd(-1)),
+        ]);
+        let rolled = tree().roll_up(&totals);
+        assert_eq!(6, rolled.len());
+        assert_eq!(usd(-128600), rolled["Housing"]);
+        assert_eq!(usd(-8500), rolled["Utilities"]);
+        assert_eq!(usd(-2500), rolled["Water"]);
+        assert!(rolled["Food"].is_zero());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,67 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            exchange_rate.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     A rate of exchange between two currencies.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use crate::currency::Currency;
+use crate::money::Money;
+use chrono::{offset::Utc, DateTime};
+use serde::{Deserialize, Serialize};
+
+///////////////////////////////////////////////////////////////////////////////
+// ExchangeRate
+//  The value of one unit of the "from" currency in the "to" currency, as of a
+//  particular date. The rate is a fixed-point number: rate / 10^scale.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExchangeRate {
+    pub id: i32,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub date: DateTime<Utc>,
+    pub from: Currency,
+    pub to: Currency,
+    pub rate: i64,
+    pub scale: u32,
+}
+
+impl ExchangeRate {
+    // Convert an amount in the "from" currency to the "to" currency.
+    pub fn convert(&self, money: Money) -> Option<Money> {
+        if money.currency() != self.from {
+            return None;
+        }
+        money.convert(self.to, self.rate, 10i64.checked_pow(self.scale)?)
+    }
+
+    // Convert an amount in the "to" currency back to the "from" currency.
+    pub fn convert_inverse(&self, money: Money) -> Option<Money> {
+        if money.currency() != self.to {
+            return None;
+        }
+        money.convert(self.from, 10i64.checked_pow(self.scale)?, self.rate)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
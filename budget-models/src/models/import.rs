@@ -0,0 +1,131 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            import.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Identification of imported transactions, so that
+//                  re-imports and overlapping statements can be detected.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::{NewTransaction, TransactionData};
+
+// How far apart the dates of two transactions of the same amount on the same
+// account may be, for them to be flagged as possibly the same transaction.
+pub const FUZZY_MATCH_DAYS: i64 = 3;
+
+///////////////////////////////////////////////////////////////////////////////
+// Fingerprint
+// A stable identifier of an imported leg, built from the account, the date,
+// the amount, the normalized description and an id of the line in the
+// source. Importing the same line again produces the same fingerprint.
+////
+
+// Lowercase, with anything other than letters and digits collapsed into
+// single spaces, so that differences in formatting between exports of the
+// same statement don't change the fingerprint.
+pub fn normalize_description(description: &str) -> String {
+    description
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+pub fn fingerprint(
+    account: &str,
+    date: NaiveDate,
+    amount: i64,
+    description: &str,
+    source_id: &str,
+) -> String {
+    format!(
+        "{}|{}|{}|{}|{}",
+        account,
+        date.format("%Y-%m-%d"),
+        amount,
+        normalize_description(description),
+        source_id
+    )
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ImportMatch
+// How a transaction being imported relates to the transactions that are
+// already recorded. Existing transactions are referred to by leg id.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ImportMatch {
+    // Nothing recorded resembles the transaction.
+    New,
+
+    // A leg of the transaction has already been imported.
+    Duplicate { existing: i32 },
+
+    // Legs of the same amount on the same account, within FUZZY_MATCH_DAYS,
+    // which may be the same transaction. These need review before the
+    // transaction is imported.
+    Possible { candidates: Vec<i32> },
+}
+
+pub fn match_import(
+    transaction: &NewTransaction,
+    existing: &[&TransactionData],
+) -> ImportMatch {
+    let legs = transaction.series.legs();
+    let duplicate = legs.iter().find_map(|leg| {
+        existing
+            .iter()
+            .find(|other| {
+                leg.fingerprint.is_some()
+                    && other.fingerprint == leg.fingerprint
+            })
+            .map(|other| other.id)
+    });
+    if let Some(existing) = duplicate {
+        return ImportMatch::Duplicate { existing };
+    }
+
+    let window = Duration::days(FUZZY_MATCH_DAYS);
+    let candidates = existing
+        .iter()
+        .filter(|other| {
+            legs.iter().any(|leg| {
+                leg.account == other.account
+                    && leg.amount == other.amount
+                    && other.date >= leg.date - window
+                    && other.date <= leg.date + window
+            })
+        })
+        .map(|other| other.id)
+        .collect::<Vec<i32>>();
+    if candidates.is_empty() {
+        ImportMatch::New
+    } else {
+        ImportMatch::Possible { candidates }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
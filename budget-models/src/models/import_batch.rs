@@ -0,0 +1,57 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            import_batch.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     A record of one invocation of an import.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use chrono::{offset::Utc, DateTime};
+use serde::{Deserialize, Serialize};
+
+use super::TransactionType;
+
+///////////////////////////////////////////////////////////////////////////////
+// ImportBatch
+// The transactions created by one import of a file. Every leg created by the
+// import refers to its batch, so the import can be undone.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ImportBatch {
+    pub id: i32,
+    pub filename: String,
+    // Hex digest of the contents of the file.
+    pub hash: String,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub imported_at: DateTime<Utc>,
+    pub transaction_type: TransactionType,
+    pub row_count: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct NewImportBatch {
+    pub filename: String,
+    pub hash: String,
+    pub transaction_type: TransactionType,
+}
+
+///////////////////////////////////////////////////////////////////////////////
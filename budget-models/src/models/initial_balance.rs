@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -26,8 +26,9 @@
 
 use chrono::{offset::Utc, DateTime};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InitialBalance {
     pub id: i32,
     pub account: String,
@@ -36,9 +37,13 @@ pub struct InitialBalance {
 
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub last_updated: DateTime<Utc>,
+
+    // The difference from the closing balance of the previous period, as of
+    // the last time the opening balances were computed.
+    pub adjustment: Option<i64>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct NewInitialBalance {
     pub account: String,
     pub budget: i32,
@@ -46,3 +51,60 @@ pub struct NewInitialBalance {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+// OpeningBalance
+//  The balance of an account at the start of a budget period. An explicit
+//  initial balance takes precedence over the closing balance carried from the
+//  previous period. When both exist and disagree, the difference is an
+//  adjustment, e.g. for a transaction that was never recorded, and it's
+//  recorded with the initial balance.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OpeningBalance {
+    pub account: String,
+    pub budget: i32,
+    pub balance: i64,
+    pub carried: Option<i64>,
+    pub explicit: Option<i64>,
+}
+
+impl OpeningBalance {
+    // The amount by which the explicit initial balance differs from the
+    // closing balance of the previous period, if it differs at all.
+    pub fn adjustment(&self) -> Option<i64> {
+        match (self.explicit, self.carried) {
+            (Some(explicit), Some(carried)) if explicit != carried => {
+                Some(explicit - carried)
+            }
+            _ => None,
+        }
+    }
+}
+
+// Compute the opening balance of an account for each budget period. Periods
+// are given in order as (budget, net change in the account during the
+// period). The first period opens at zero unless it has an explicit initial
+// balance.
+pub fn opening_balances<S: AsRef<str>>(
+    account: S,
+    periods: &[(i32, i64)],
+    explicit: &HashMap<i32, i64>,
+) -> Vec<OpeningBalance> {
+    let mut balances = Vec::new();
+    let mut closing: Option<i64> = None;
+    for (budget, net) in periods {
+        let explicit = explicit.get(budget).copied();
+        let balance = explicit.or(closing).unwrap_or_default();
+        balances.push(OpeningBalance {
+            account: account.as_ref().to_string(),
+            budget: *budget,
+            balance,
+            carried: closing,
+            explicit,
+        });
+        closing = Some(balance + net);
+    }
+    balances
+}
+
+///////////////////////////////////////////////////////////////////////////////
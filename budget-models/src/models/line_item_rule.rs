@@ -0,0 +1,60 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            line_item_rule.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Rules for assigning line items to imported transactions.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use serde::{Deserialize, Serialize};
+
+///////////////////////////////////////////////////////////////////////////////
+// LineItemRule
+// Assigns a line item to an imported transaction which matches every one of
+// the conditions that are set. Rules are tried in ascending order of
+// priority, and the first which matches wins. The amount is in the currency
+// of the account, and is negative for money leaving it.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LineItemRule {
+    pub id: i32,
+    pub priority: i32,
+    pub line_item: String,
+    pub account: Option<String>,
+    // A regular expression which the description must match.
+    pub pattern: Option<String>,
+    // Text which the description must contain, ignoring case.
+    pub contains: Option<String>,
+    pub amount: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NewLineItemRule {
+    pub priority: i32,
+    pub line_item: String,
+    pub account: Option<String>,
+    pub pattern: Option<String>,
+    pub contains: Option<String>,
+    pub amount: Option<i64>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
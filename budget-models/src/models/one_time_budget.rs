@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,12 +24,117 @@
 // limitations under the License.
 ////
 
+use chrono::{offset::Utc, DateTime};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+use crate::Money;
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize,
+)]
+pub enum OneTimeBudgetStatus {
+    #[default]
+    Active,
+    Completed,
+    Cancelled,
+}
+
+// A budget for a project or a sinking fund, e.g. "kitchen remodel, $15k,
+// spans 8 months". If the budget has an account, only transactions on that
+// account count towards its progress. Otherwise, every transaction attached
+// to the budget counts, and transfers count both towards funding and
+// spending.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct OneTimeBudget {
     pub id: i32,
     pub description: String,
+    pub target: i64,
+    pub account: Option<String>,
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub end_date: Option<DateTime<Utc>>,
+    pub status: OneTimeBudgetStatus,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct NewOneTimeBudget {
+    pub description: String,
+    pub target: i64,
+    pub account: Option<String>,
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+impl OneTimeBudget {
+    // Whether the date falls within the date range of the budget. A budget
+    // without a start or end date is open on that side.
+    pub fn contains(&self, date: &DateTime<Utc>) -> bool {
+        self.start_date.iter().all(|start| start <= date)
+            && self.end_date.iter().all(|end| date <= end)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// OneTimeBudgetProgress
+//  Progress towards the target of a one-time budget. Inflows fund the budget,
+//  and outflows spend from it.
+////
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OneTimeBudgetProgress {
+    pub target: Money,
+    pub funded: Money,
+    pub spent: Money,
+    pub planned: Money,
+}
+
+impl OneTimeBudgetProgress {
+    // Calculate progress from the amounts allocated to the budget by real
+    // and planned transactions, which must be in the currency of the target.
+    pub fn new<R, P>(target: Money, real: R, planned: P) -> Self
+    where
+        R: IntoIterator<Item = Money>,
+        P: IntoIterator<Item = Money>,
+    {
+        let zero = Money::zero(target.currency());
+        let mut progress = OneTimeBudgetProgress {
+            target,
+            funded: zero,
+            spent: zero,
+            planned: zero,
+        };
+        for amount in real {
+            if amount.is_negative() {
+                progress.spent -= amount;
+            } else {
+                progress.funded += amount;
+            }
+        }
+        progress.planned = planned
+            .into_iter()
+            .filter(|amount| amount.is_negative())
+            .map(|amount| -amount)
+            .fold(zero, |total, amount| total + amount);
+        progress
+    }
+
+    // The amount of the target which has not been spent yet.
+    pub fn remaining(&self) -> Money {
+        self.target - self.spent
+    }
+
+    // The amount of the target which has not been funded yet.
+    pub fn unfunded(&self) -> Money {
+        self.target - self.funded
+    }
+
+    // Funds which have been set aside, but not spent.
+    pub fn available(&self) -> Money {
+        self.funded - self.spent
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
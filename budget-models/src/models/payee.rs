@@ -0,0 +1,71 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            payee.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Payees: the canonical names of the parties that
+//                  transactions are made with.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use serde::{Deserialize, Serialize};
+
+///////////////////////////////////////////////////////////////////////////////
+// Payee
+// The canonical name of a merchant, or of anyone else that transactions are
+// made with. Banks describe the same payee in many ways, e.g.
+// "POS DEBIT 1234 KROGER #567 MADISON WI", so each payee has aliases: regular
+// expressions which are matched against the description of a transaction,
+// ignoring case. Imported transactions are assigned the payee of the first
+// alias which matches them.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Payee {
+    pub id: i32,
+    pub name: String,
+    pub aliases: Vec<PayeeAlias>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NewPayee {
+    pub name: String,
+    // The patterns of the aliases to create along with the payee.
+    pub aliases: Vec<String>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// PayeeAlias
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PayeeAlias {
+    pub id: i32,
+    pub payee: i32,
+    pub pattern: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NewPayeeAlias {
+    pub payee: i32,
+    pub pattern: String,
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -27,7 +27,7 @@
 use chrono::{offset::Utc, DateTime};
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PeriodicBudget {
     pub id: i32,
     #[serde(with = "chrono::serde::ts_milliseconds")]
@@ -36,4 +36,12 @@ pub struct PeriodicBudget {
     pub end_date: DateTime<Utc>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NewPeriodicBudget {
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub start_date: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub end_date: DateTime<Utc>,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
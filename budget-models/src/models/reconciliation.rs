@@ -0,0 +1,418 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            reconciliation.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Reconciliation of recorded transactions against balance
+//                  snapshots.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use chrono::{offset::Utc, DateTime, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{BalanceSnapshot, TransactionData};
+
+// How far outside of an interval a transaction may be and still be suspected
+// of being recorded with the wrong date.
+const MISDATED_DAYS: i64 = 7;
+
+///////////////////////////////////////////////////////////////////////////////
+// SnapshotInterval
+// The period between two consecutive balance snapshots of an account. The
+// expected balance is the amount of the later snapshot, and the calculated
+// balance is the amount of the earlier snapshot plus every transaction dated
+// on or after the earlier snapshot, and before the later one.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SnapshotInterval {
+    pub account: String,
+    // The id of the snapshot which ends the interval.
+    pub snapshot: i32,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub start_date: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub end_date: DateTime<Utc>,
+    pub expected: i64,
+    pub calculated: i64,
+}
+
+impl SnapshotInterval {
+    // The amount that would need to be recorded in the interval for the
+    // calculated balance to match the snapshot.
+    pub fn delta(&self) -> i64 {
+        self.expected - self.calculated
+    }
+
+    pub fn contains(&self, date: &DateTime<Utc>) -> bool {
+        date >= &self.start_date && date < &self.end_date
+    }
+}
+
+// Calculate every interval between consecutive snapshots of the account.
+// Snapshots and legs of other accounts are ignored.
+pub fn snapshot_intervals(
+    account: &str,
+    snapshots: &[BalanceSnapshot],
+    legs: &[TransactionData],
+) -> Vec<SnapshotInterval> {
+    let mut snapshots = snapshots
+        .iter()
+        .filter(|snapshot| snapshot.account == account)
+        .collect::<Vec<&BalanceSnapshot>>();
+    snapshots.sort_by_key(|snapshot| snapshot.date);
+
+    snapshots
+        .windows(2)
+        .map(|pair| {
+            let (previous, snapshot) = (pair[0], pair[1]);
+            let calculated = previous.amount
+                + legs
+                    .iter()
+                    .filter(|leg| {
+                        leg.account == account
+                            && leg.date >= previous.date
+                            && leg.date < snapshot.date
+                    })
+                    .map(|leg| leg.amount)
+                    .sum::<i64>();
+            SnapshotInterval {
+                account: account.to_string(),
+                snapshot: snapshot.id,
+                start_date: previous.date,
+                end_date: snapshot.date,
+                expected: snapshot.amount,
+                calculated,
+            }
+        })
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LikelyCause
+// A suggested explanation for the difference between the calculated and
+// expected balance of an interval. Transactions are referred to by the id of
+// the leg on the account being reconciled.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum LikelyCause {
+    // A transaction of exactly the delta was never recorded. Any transactions
+    // of that amount just outside of the interval may have been recorded
+    // with the wrong date.
+    Missing { amount: i64, misdated: Vec<i32> },
+
+    // The same transaction appears to have been recorded twice, and removing
+    // one of them would account for the delta.
+    Duplicate { original: i32, duplicate: i32 },
+
+    // The transaction appears to have been recorded with the wrong sign.
+    SignFlip { transaction: i32 },
+}
+
+// Suggest the likely causes of the interval's delta, most likely first.
+// Returns nothing if the interval balances.
+pub fn likely_causes(
+    interval: &SnapshotInterval,
+    legs: &[TransactionData],
+) -> Vec<LikelyCause> {
+    let delta = interval.delta();
+    if delta == 0 {
+        return Vec::new();
+    }
+
+    let legs = legs
+        .iter()
+        .filter(|leg| leg.account == interval.account)
+        .collect::<Vec<&TransactionData>>();
+    let within = legs
+        .iter()
+        .filter(|leg| interval.contains(&leg.date))
+        .copied()
+        .collect::<Vec<&TransactionData>>();
+    let mut causes = Vec::new();
+
+    // Flipping the sign of a transaction changes the balance by twice its
+    // amount.
+    causes.extend(within.iter().filter(|leg| -2 * leg.amount == delta).map(
+        |leg| LikelyCause::SignFlip {
+            transaction: leg.id,
+        },
+    ));
+
+    // Transactions on the same day for the same amount, where removing one
+    // would account for the delta.
+    let mut seen: HashMap<(i64, NaiveDate), i32> = HashMap::new();
+    for leg in within.iter().filter(|leg| leg.amount == -delta) {
+        let key = (leg.amount, leg.date.date_naive());
+        match seen.get(&key) {
+            Some(original) => causes.push(LikelyCause::Duplicate {
+                original: *original,
+                duplicate: leg.id,
+            }),
+            None => {
+                seen.insert(key, leg.id);
+            }
+        }
+    }
+
+    let window = Duration::days(MISDATED_DAYS);
+    let misdated = legs
+        .iter()
+        .filter(|leg| {
+            leg.amount == delta
+                && !interval.contains(&leg.date)
+                && leg.date >= interval.start_date - window
+                && leg.date < interval.end_date + window
+        })
+        .map(|leg| leg.id)
+        .collect();
+    causes.push(LikelyCause::Missing {
+        amount: delta,
+        misdated,
+    });
+
+    causes
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Discrepancy
+// An interval whose calculated balance doesn't match its snapshot.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Discrepancy {
+    pub interval: SnapshotInterval,
+    pub causes: Vec<LikelyCause>,
+}
+
+// Find every interval of the account that doesn't balance, along with its
+// likely causes.
+pub fn discrepancies(
+    account: &str,
+    snapshots: &[BalanceSnapshot],
+    legs: &[TransactionData],
+) -> Vec<Discrepancy> {
+    snapshot_intervals(account, snapshots, legs)
+        .into_iter()
+        .filter(|interval| interval.delta() != 0)
+        .map(|interval| Discrepancy {
+            causes: likely_causes(&interval, legs),
+            interval,
+        })
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reconciliation
+// A record that an interval has been reconciled. Transactions of the account
+// in a reconciled interval are locked against edits. If the user accepted
+// the delta of the interval, adjustment is the transaction that was recorded
+// to account for it.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Reconciliation {
+    pub id: i32,
+    pub account: String,
+    pub snapshot: i32,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub start_date: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub end_date: DateTime<Utc>,
+    pub adjustment: Option<i32>,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub reconciled_at: DateTime<Utc>,
+}
+
+impl Reconciliation {
+    pub fn locks(&self, account: &str, date: &DateTime<Utc>) -> bool {
+        self.account == account
+            && date >= &self.start_date
+            && date < &self.end_date
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const ACCOUNT: &str = "Checking";
+
+    fn date(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, 10, day, 0, 0, 0).unwrap()
+    }
+
+    fn leg(id: i32, amount: i64, day: u32) -> TransactionData {
+        TransactionData {
+            id,
+            summary: format!("Leg {}", id),
+            account: ACCOUNT.to_string(),
+            amount,
+            date: date(day),
+            ..Default::default()
+        }
+    }
+
+    fn snapshot(id: i32, day: u32, amount: i64) -> BalanceSnapshot {
+        BalanceSnapshot {
+            id,
+            account: ACCOUNT.to_string(),
+            date: date(day),
+            amount,
+        }
+    }
+
+    // The interval from the 10th up to the 20th.
+    fn interval(expected: i64, calculated: i64) -> SnapshotInterval {
+        SnapshotInterval {
+            account: ACCOUNT.to_string(),
+            snapshot: 2,
+            start_date: date(10),
+            end_date: date(20),
+            expected,
+            calculated,
+        }
+    }
+
+    #[test]
+    fn intervals_are_half_open() {
+        let other = TransactionData {
+            account: "Savings".to_string(),
+            ..leg(4, 500, 12)
+        };
+        let legs = vec![leg(1, -100, 10), leg(2, -50, 15), leg(3, -25, 20)];
+        let snapshots = vec![
+            snapshot(3, 30, 800),
+            snapshot(1, 10, 1000),
+            snapshot(2, 20, 850),
+        ];
+        let intervals = snapshot_intervals(
+            ACCOUNT,
+            &snapshots,
+            &[legs, vec![other]].concat(),
+        );
+        assert_eq!(
+            vec![(2, 850, 850), (3, 800, 825)],
+            intervals
+                .iter()
+                .map(|i| (i.snapshot, i.expected, i.calculated))
+                .collect::<Vec<(i32, i64, i64)>>()
+        );
+        assert_eq!(-25, intervals[1].delta());
+    }
+
+    #[test]
+    fn balanced_intervals_have_no_causes() {
+        assert!(
+            likely_causes(&interval(500, 500), &[leg(1, 50, 12)]).is_empty()
+        );
+    }
+
+    #[test]
+    fn sign_flip_accounts_for_twice_the_amount() {
+        // A refund of 50 recorded as a charge.
+        let legs = vec![leg(1, -50, 12), leg(2, -100, 14)];
+        assert_eq!(
+            vec![
+                LikelyCause::SignFlip { transaction: 1 },
+                LikelyCause::Missing {
+                    amount: 100,
+                    misdated: vec![],
+                },
+            ],
+            likely_causes(&interval(600, 500), &legs)
+        );
+    }
+
+    #[test]
+    fn duplicate_accounts_for_the_amount() {
+        // Only legs on the same day are duplicates.
+        let legs = vec![
+            leg(1, -30, 12),
+            leg(2, -30, 13),
+            leg(3, -30, 12),
+            leg(4, -30, 12),
+        ];
+        assert_eq!(
+            vec![
+                LikelyCause::Duplicate {
+                    original: 1,
+                    duplicate: 3,
+                },
+                LikelyCause::Duplicate {
+                    original: 1,
+                    duplicate: 4,
+                },
+                LikelyCause::Missing {
+                    amount: 30,
+                    misdated: vec![],
+                },
+            ],
+            likely_causes(&interval(530, 500), &legs)
+        );
+    }
+
+    #[test]
+    fn missing_includes_nearby_misdated_legs() {
+        // The leg on the 20th was counted in the next interval.
+        let legs = vec![
+            leg(1, -40, 2),
+            leg(2, -40, 3),
+            leg(3, -40, 20),
+            leg(4, -40, 27),
+            leg(5, -40, 15),
+        ];
+        assert_eq!(
+            vec![LikelyCause::Missing {
+                amount: -40,
+                misdated: vec![2, 3],
+            }],
+            likely_causes(&interval(460, 500), &legs)
+        );
+    }
+
+    #[test]
+    fn reconciliation_locks_the_half_open_interval() {
+        let reconciliation = Reconciliation {
+            id: 1,
+            account: ACCOUNT.to_string(),
+            snapshot: 2,
+            start_date: date(10),
+            end_date: date(20),
+            adjustment: None,
+            reconciled_at: date(21),
+        };
+        assert!(
+            !reconciliation.locks(ACCOUNT, &(date(10) - Duration::days(1)))
+        );
+        assert!(reconciliation.locks(ACCOUNT, &date(10)));
+        assert!(
+            reconciliation.locks(ACCOUNT, &(date(20) - Duration::seconds(1)))
+        );
+        assert!(!reconciliation.locks(ACCOUNT, &date(20)));
+        assert!(!reconciliation.locks("Savings", &date(15)));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
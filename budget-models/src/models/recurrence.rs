@@ -0,0 +1,389 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            recurrence.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Rules for transactions that recur on a schedule.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use chrono::{
+    offset::Utc, DateTime, Datelike, Duration, NaiveDate, TimeZone, Weekday,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Allocation, CondensedTransaction, TransactionType};
+use crate::{Currency, Money};
+
+///////////////////////////////////////////////////////////////////////////////
+// DayOfMonth
+//  Days past the end of a short month are clamped to the last day of the
+//  month, so Day(31) falls on the 30th of April. Business days are Monday
+//  through Friday; holidays are not considered.
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum DayOfMonth {
+    Day(u32),
+    LastDay,
+    LastBusinessDay,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Frequency
+//  How often a rule recurs. The interval allows for e.g. "every other Friday"
+//  (weekly, with an interval of two, starting on a Friday). Weekly and yearly
+//  rules recur on the weekday or anniversary of the start date.
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum Frequency {
+    Weekly { interval: u32 },
+    Monthly { interval: u32, day: DayOfMonth },
+    Yearly { interval: u32 },
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum RecurrenceEnd {
+    Never,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    Until(DateTime<Utc>),
+    Count(u32),
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RecurrenceRule
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct RecurrenceRule {
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub start: DateTime<Utc>,
+    pub frequency: Frequency,
+    pub end: RecurrenceEnd,
+}
+
+impl RecurrenceRule {
+    pub fn occurrences(&self) -> Occurrences {
+        Occurrences {
+            rule: *self,
+            period: 0,
+            count: 0,
+        }
+    }
+
+    // All occurrences of the rule in the range [start, end].
+    pub fn occurrences_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        self.occurrences()
+            .skip_while(|date| *date < start)
+            .take_while(|date| *date <= end)
+            .collect()
+    }
+}
+
+fn last_day_of_month(year: i32, month: u32) -> NaiveDate {
+    let (year, month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|date| date.pred_opt())
+        .unwrap()
+}
+
+fn day_of_month(year: i32, month: u32, day: DayOfMonth) -> NaiveDate {
+    let last = last_day_of_month(year, month);
+    match day {
+        DayOfMonth::Day(day) => {
+            last.with_day(day.clamp(1, last.day())).unwrap()
+        }
+        DayOfMonth::LastDay => last,
+        DayOfMonth::LastBusinessDay => match last.weekday() {
+            Weekday::Sat => last - Duration::days(1),
+            Weekday::Sun => last - Duration::days(2),
+            _ => last,
+        },
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Occurrences
+//  Iterator over the dates on which a rule recurs, in order. Each occurrence
+//  has the same time of day as the start of the rule.
+////
+
+pub struct Occurrences {
+    rule: RecurrenceRule,
+    period: u32,
+    count: u32,
+}
+
+impl Occurrences {
+    // The date of the nth period of the rule, which may precede the start of
+    // the rule (e.g. the 1st of the month when the rule starts on the 15th).
+    fn period_date(&self, period: u32) -> Option<NaiveDate> {
+        let start = self.rule.start.date_naive();
+        match self.rule.frequency {
+            Frequency::Weekly { interval } => start.checked_add_signed(
+                Duration::weeks(period as i64 * interval.max(1) as i64),
+            ),
+            Frequency::Monthly { interval, day } => {
+                let months = start.month0() as i64
+                    + period as i64 * interval.max(1) as i64;
+                let year = start.year() as i64 + months / 12;
+                let month = (months % 12) as u32 + 1;
+                Some(day_of_month(i32::try_from(year).ok()?, month, day))
+            }
+            Frequency::Yearly { interval } => {
+                let year = start.year() as i64
+                    + period as i64 * interval.max(1) as i64;
+                Some(day_of_month(
+                    i32::try_from(year).ok()?,
+                    start.month(),
+                    DayOfMonth::Day(start.day()),
+                ))
+            }
+        }
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime<Utc>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let RecurrenceEnd::Count(count) = self.rule.end {
+            if self.count >= count {
+                return None;
+            }
+        }
+
+        let start = self.rule.start;
+        let occurrence = loop {
+            let date = self.period_date(self.period)?;
+            self.period = self.period.checked_add(1)?;
+            let occurrence =
+                Utc.from_utc_datetime(&date.and_time(start.time()));
+            if occurrence >= start {
+                break occurrence;
+            }
+        };
+
+        if let RecurrenceEnd::Until(end) = self.rule.end {
+            if occurrence > end {
+                return None;
+            }
+        }
+
+        self.count += 1;
+        Some(occurrence)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RecurringTransaction
+//  A planned transaction which recurs according to a rule. Each occurrence is
+//  instantiated as a planned transaction in the periodic budget containing it.
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RecurringTransaction {
+    pub id: i32,
+    pub summary: String,
+    pub from_account: Option<String>,
+    pub to_account: Option<String>,
+    pub amount: i64,
+    pub line_item: String,
+    pub rule: RecurrenceRule,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct NewRecurringTransaction {
+    pub summary: String,
+    pub from_account: Option<String>,
+    pub to_account: Option<String>,
+    pub amount: i64,
+    pub line_item: String,
+    pub rule: RecurrenceRule,
+}
+
+impl RecurringTransaction {
+    // The planned transaction for one occurrence of this transaction, whose
+    // amount is in the currency of its accounts.
+    pub fn instantiate(
+        &self,
+        date: DateTime<Utc>,
+        periodic_budget: i32,
+        currency: Currency,
+    ) -> CondensedTransaction {
+        CondensedTransaction {
+            summary: self.summary.clone(),
+            line_items: vec![Allocation {
+                line_item: self.line_item.clone(),
+                amount: self.amount,
+            }],
+            date: date.into(),
+            from_account: self.from_account.clone(),
+            to_account: self.to_account.clone(),
+            amount: Money::new(self.amount, currency),
+            periodic_budget: Some(periodic_budget),
+            transaction_type: TransactionType::Planned,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+    }
+
+    fn recurring(
+        start: DateTime<Utc>,
+        frequency: Frequency,
+    ) -> RecurrenceRule {
+        RecurrenceRule {
+            start,
+            frequency,
+            end: RecurrenceEnd::Never,
+        }
+    }
+
+    fn monthly(interval: u32, day: DayOfMonth) -> Frequency {
+        Frequency::Monthly { interval, day }
+    }
+
+    #[test]
+    fn end_of_month_is_clamped() {
+        let rule =
+            recurring(date(2023, 1, 31), monthly(1, DayOfMonth::Day(31)));
+        assert_eq!(
+            vec![
+                date(2023, 1, 31),
+                date(2023, 2, 28),
+                date(2023, 3, 31),
+                date(2023, 4, 30),
+            ],
+            rule.occurrences().take(4).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn leap_day_falls_back_to_the_28th() {
+        let rule =
+            recurring(date(2024, 2, 29), Frequency::Yearly { interval: 1 });
+        assert_eq!(
+            vec![
+                date(2024, 2, 29),
+                date(2025, 2, 28),
+                date(2026, 2, 28),
+                date(2027, 2, 28),
+                date(2028, 2, 29),
+            ],
+            rule.occurrences().take(5).collect::<Vec<_>>()
+        );
+
+        let rule =
+            recurring(date(2024, 1, 31), monthly(1, DayOfMonth::LastDay));
+        assert_eq!(Some(date(2024, 2, 29)), rule.occurrences().nth(1));
+    }
+
+    #[test]
+    fn last_business_day_skips_the_weekend() {
+        // 30 September 2023 is a Saturday.
+        let rule = recurring(
+            date(2023, 9, 1),
+            monthly(1, DayOfMonth::LastBusinessDay),
+        );
+        assert_eq!(Some(date(2023, 9, 29)), rule.occurrences().next());
+    }
+
+    #[test]
+    fn periods_before_the_start_are_skipped() {
+        let rule =
+            recurring(date(2023, 1, 15), monthly(1, DayOfMonth::Day(1)));
+        assert_eq!(Some(date(2023, 2, 1)), rule.occurrences().next());
+    }
+
+    #[test]
+    fn until_is_inclusive() {
+        let rule = RecurrenceRule {
+            end: RecurrenceEnd::Until(date(2023, 1, 15)),
+            ..recurring(date(2023, 1, 1), Frequency::Weekly { interval: 1 })
+        };
+        assert_eq!(
+            vec![date(2023, 1, 1), date(2023, 1, 8), date(2023, 1, 15)],
+            rule.occurrences().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn count_limits_the_occurrences() {
+        let rule = RecurrenceRule {
+            end: RecurrenceEnd::Count(2),
+            ..recurring(date(2023, 1, 15), monthly(1, DayOfMonth::Day(1)))
+        };
+        assert_eq!(
+            vec![date(2023, 2, 1), date(2023, 3, 1)],
+            rule.occurrences().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn intervals_skip_periods() {
+        let rule =
+            recurring(date(2023, 1, 6), Frequency::Weekly { interval: 2 });
+        assert_eq!(
+            vec![date(2023, 1, 6), date(2023, 1, 20), date(2023, 2, 3)],
+            rule.occurrences().take(3).collect::<Vec<_>>()
+        );
+
+        let rule =
+            recurring(date(2023, 11, 30), monthly(3, DayOfMonth::Day(30)));
+        assert_eq!(
+            vec![date(2023, 11, 30), date(2024, 2, 29), date(2024, 5, 30)],
+            rule.occurrences().take(3).collect::<Vec<_>>()
+        );
+
+        let rule =
+            recurring(date(2024, 2, 29), Frequency::Yearly { interval: 4 });
+        assert_eq!(
+            vec![date(2024, 2, 29), date(2028, 2, 29)],
+            rule.occurrences().take(2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn occurrences_between_is_inclusive() {
+        let rule = recurring(date(2023, 1, 1), monthly(1, DayOfMonth::Day(1)));
+        assert_eq!(
+            vec![date(2023, 3, 1), date(2023, 4, 1)],
+            rule.occurrences_between(date(2023, 3, 1), date(2023, 4, 1))
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,7 @@
 //
 // CREATED:         09/17/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -27,10 +27,40 @@
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Tag {
     pub id: i32,
     pub tag: String,
 }
 
+// Select transactions by their tags. An empty filter matches nothing when
+// any tag is required, and everything when all tags are required.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TagFilter {
+    Any(Vec<String>),
+    All(Vec<String>),
+}
+
+impl TagFilter {
+    pub fn tags(&self) -> &[String] {
+        match self {
+            Self::Any(tags) | Self::All(tags) => tags,
+        }
+    }
+
+    // Whether every transaction is selected by the filter.
+    pub fn selects_all(&self) -> bool {
+        matches!(self, Self::All(tags) if tags.is_empty())
+    }
+
+    // Whether a transaction with the given tags is selected by the filter.
+    pub fn matches<S: AsRef<str>>(&self, tags: &[S]) -> bool {
+        let has = |tag: &String| tags.iter().any(|t| t.as_ref() == tag);
+        match self {
+            Self::Any(wanted) => wanted.iter().any(has),
+            Self::All(wanted) => wanted.iter().all(has),
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
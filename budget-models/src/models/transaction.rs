@@ -7,7 +7,7 @@
 //
 // CREATED:         07/05/2022
 //
-// LAST EDITED:     11/20/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,12 +24,11 @@
 // limitations under the License.
 ////
 
-use crate::money::Money;
+use super::{fingerprint, TagFilter, ValidationError, Violation};
+use crate::money::{allocate, Money};
 use chrono::{offset::FixedOffset, offset::Utc, DateTime};
 use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
-use std::error::Error;
-use std::fmt;
 use table_iter::prelude::*;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -45,10 +44,17 @@ pub struct TransactionData {
     pub summary: String,
     pub account: String,
     pub amount: i64,
-    pub completed_by: Option<i32>,
+    pub series: Option<i32>,
 
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub date: DateTime<Utc>,
+
+    // Identifies the source of an imported leg. See fingerprint().
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+
+    #[serde(default)]
+    pub payee: Option<i32>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
@@ -59,19 +65,114 @@ pub struct NewTransactionData {
 
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub date: DateTime<Utc>,
+
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+
+    #[serde(default)]
+    pub payee: Option<i32>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Allocation
+// The portion of a transaction's amount that is attributed to a line item.
+////
+
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Allocation {
+    pub line_item: String,
+    pub amount: i64,
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 // TransactionMetadata
-// Associates a transaction to a budget and line item. "Real" transactions
+// Associates a transaction to a budget and line items. "Real" transactions
 // aren't necessarily associated with a budget, though they may be. Planned
-// transactions must be associated with a budget.
+// transactions must be associated with a budget. The budget may be periodic,
+// one-time (e.g. a project or a sinking fund), or both. A transaction may be
+// split across several line items, in which case the allocations must sum to
+// the amount of the transaction.
 ////
 
 #[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TransactionMetadata {
-    pub periodic_budget: i32,
-    pub line_item: String,
+    pub periodic_budget: Option<i32>,
+    pub one_time_budget: Option<i32>,
+    pub allocations: Vec<Allocation>,
+}
+
+impl TransactionMetadata {
+    // Metadata allocating the whole amount of a transaction to one line item.
+    pub fn single<S: AsRef<str>>(
+        periodic_budget: i32,
+        line_item: S,
+        amount: i64,
+    ) -> Self {
+        Self {
+            periodic_budget: Some(periodic_budget),
+            one_time_budget: None,
+            allocations: vec![Allocation {
+                line_item: line_item.as_ref().to_string(),
+                amount,
+            }],
+        }
+    }
+
+    pub fn violations(&self, amount: i64) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self.periodic_budget.is_none() && self.one_time_budget.is_none() {
+            violations.push(Violation::NoBudget);
+        }
+
+        if self.allocations.is_empty() {
+            violations.push(Violation::Unallocated);
+        } else {
+            let allocated: i64 =
+                self.allocations.iter().map(|a| a.amount).sum();
+            if allocated != amount {
+                violations
+                    .push(Violation::AllocationMismatch { allocated, amount });
+            }
+        }
+        violations
+    }
+
+    pub fn validate(&self, amount: i64) -> Result<(), ValidationError> {
+        ValidationError::check(self.violations(amount))
+    }
+
+    // Scale the allocations in proportion to one leg of a transaction series,
+    // such that they sum to the amount of that leg.
+    pub fn apportion(&self, amount: i64) -> Vec<Allocation> {
+        let allocated: i64 = self.allocations.iter().map(|a| a.amount).sum();
+        if allocated == amount || allocated == -amount {
+            let sign = if allocated == amount { 1 } else { -1 };
+            return self
+                .allocations
+                .iter()
+                .map(|allocation| Allocation {
+                    line_item: allocation.line_item.clone(),
+                    amount: sign * allocation.amount,
+                })
+                .collect();
+        }
+
+        let ratios = self
+            .allocations
+            .iter()
+            .map(|a| a.amount.unsigned_abs())
+            .collect::<Vec<u64>>();
+        let shares =
+            allocate(amount, &ratios).unwrap_or_else(|| vec![0; ratios.len()]);
+        self.allocations
+            .iter()
+            .zip(shares)
+            .map(|(allocation, share)| Allocation {
+                line_item: allocation.line_item.clone(),
+                amount: share,
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -92,29 +193,135 @@ pub enum TransactionTypedMetadata {
     Planned(TransactionMetadata),
 }
 
+impl TransactionTypedMetadata {
+    pub fn metadata(&self) -> Option<&TransactionMetadata> {
+        match self {
+            Self::Real(metadata) => metadata.as_ref(),
+            Self::Planned(metadata) => Some(metadata),
+        }
+    }
+
+    pub fn violations(&self, amount: i64) -> Vec<Violation> {
+        match self {
+            Self::Real(None) => Vec::new(),
+            Self::Real(Some(metadata)) | Self::Planned(metadata) => {
+                metadata.violations(amount)
+            }
+        }
+    }
+
+    pub fn validate(&self, amount: i64) -> Result<(), ValidationError> {
+        ValidationError::check(self.violations(amount))
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // TransactionSeries
-// Since a transaction may either occur on one account, or on two (in the case
-// of a transfer), this enum allows to treat transactions in an "atomic"
-// fashion.
+// A transaction may occur on one account, or on several linked accounts (a
+// transfer, a loan payment split between principal and interest, or a
+// paycheck split between several accounts). This enum allows to treat
+// transactions in an "atomic" fashion. The legs of a linked series must sum
+// to zero.
 ////
 
 #[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub enum TransactionSeries {
     Single(TransactionData),
-    Transfer {
-        starting: TransactionData,
-        completing: TransactionData,
-    },
+    Linked(Vec<TransactionData>),
 }
 
 #[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub enum NewTransactionSeries {
     Single(NewTransactionData),
-    Transfer {
-        starting: NewTransactionData,
-        completing: NewTransactionData,
-    },
+    Linked(Vec<NewTransactionData>),
+}
+
+impl From<TransactionData> for NewTransactionData {
+    fn from(value: TransactionData) -> Self {
+        NewTransactionData {
+            summary: value.summary,
+            account: value.account,
+            amount: value.amount,
+            date: value.date,
+            fingerprint: value.fingerprint,
+            payee: value.payee,
+        }
+    }
+}
+
+impl From<TransactionSeries> for NewTransactionSeries {
+    fn from(value: TransactionSeries) -> Self {
+        match value {
+            TransactionSeries::Single(data) => Self::Single(data.into()),
+            TransactionSeries::Linked(legs) => {
+                Self::Linked(legs.into_iter().map(|leg| leg.into()).collect())
+            }
+        }
+    }
+}
+
+// The amount of a series, as seen by its line items. For a linked series,
+// this is the total amount received by the accounts in the series.
+fn series_amount<'a, I>(legs: I) -> i64
+where
+    I: Iterator<Item = &'a i64>,
+{
+    legs.filter(|amount| **amount > 0).sum()
+}
+
+impl TransactionSeries {
+    pub fn legs(&self) -> Vec<&TransactionData> {
+        match self {
+            Self::Single(data) => vec![data],
+            Self::Linked(legs) => legs.iter().collect(),
+        }
+    }
+
+    pub fn amount(&self) -> i64 {
+        match self {
+            Self::Single(data) => data.amount,
+            Self::Linked(legs) => {
+                series_amount(legs.iter().map(|l| &l.amount))
+            }
+        }
+    }
+}
+
+impl NewTransactionSeries {
+    pub fn legs(&self) -> Vec<&NewTransactionData> {
+        match self {
+            Self::Single(data) => vec![data],
+            Self::Linked(legs) => legs.iter().collect(),
+        }
+    }
+
+    pub fn amount(&self) -> i64 {
+        match self {
+            Self::Single(data) => data.amount,
+            Self::Linked(legs) => {
+                series_amount(legs.iter().map(|l| &l.amount))
+            }
+        }
+    }
+
+    pub fn violations(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if let Self::Linked(legs) = self {
+            if legs.len() < 2 {
+                violations.push(Violation::TooFewLegs { legs: legs.len() });
+            }
+
+            let balance: i64 = legs.iter().map(|leg| leg.amount).sum();
+            if balance != 0 {
+                violations.push(Violation::UnbalancedSeries { balance });
+            }
+        }
+        violations
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ValidationError::check(self.violations())
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -136,22 +343,72 @@ pub struct NewTransaction {
     pub series: NewTransactionSeries,
 }
 
+impl NewTransaction {
+    // Violations which can be found without knowing what exists in the
+    // database. See ValidationContext for the rest.
+    pub fn violations(&self) -> Vec<Violation> {
+        let mut violations = self.series.violations();
+        violations.extend(self.metadata.violations(self.series.amount()));
+        violations
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        ValidationError::check(self.violations())
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
-// InvalidTransactionError
+// TransactionFilter
+// Selects transactions for listing. Every criterion that is set must match. A
+// transaction in a linked series matches if any of its legs match the account
+// and date criteria.
 ////
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct InvalidTransactionError(String);
-impl InvalidTransactionError {
-    pub fn new<S: AsRef<str>>(message: S) -> Self {
-        Self(message.as_ref().to_string())
-    }
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TransactionFilter {
+    pub transaction_type: Option<TransactionType>,
+    pub account: Option<String>,
+    pub periodic_budget: Option<i32>,
+    pub one_time_budget: Option<i32>,
+    pub line_item: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub tags: Option<TagFilter>,
+    pub payee: Option<i32>,
 }
 
-impl Error for InvalidTransactionError {}
-impl fmt::Display for InvalidTransactionError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", &self.0)
+impl TransactionFilter {
+    // Whether the transaction is selected by the filter. Tags aren't part of
+    // the Transaction model, so the tags criterion is not checked here.
+    pub fn matches(&self, transaction: &Transaction) -> bool {
+        let leg_matches = |leg: &&TransactionData| {
+            self.account.iter().all(|account| &leg.account == account)
+                && self.payee.iter().all(|payee| leg.payee == Some(*payee))
+                && self.start_date.iter().all(|date| &leg.date >= date)
+                && self.end_date.iter().all(|date| &leg.date <= date)
+        };
+        if !transaction.series.legs().iter().any(leg_matches) {
+            return false;
+        }
+
+        let transaction_type = match &transaction.metadata {
+            TransactionTypedMetadata::Real(_) => TransactionType::Real,
+            TransactionTypedMetadata::Planned(_) => TransactionType::Planned,
+        };
+        let metadata = transaction.metadata.metadata();
+        let periodic_budget = metadata.and_then(|m| m.periodic_budget);
+        let one_time_budget = metadata.and_then(|m| m.one_time_budget);
+        let has_line_item = |line_item: &String| {
+            metadata.iter().any(|m| {
+                m.allocations.iter().any(|a| &a.line_item == line_item)
+            })
+        };
+        self.transaction_type.iter().all(|t| t == &transaction_type)
+            && (self.periodic_budget.is_none()
+                || self.periodic_budget == periodic_budget)
+            && (self.one_time_budget.is_none()
+                || self.one_time_budget == one_time_budget)
+            && self.line_item.iter().all(has_line_item)
     }
 }
 
@@ -176,8 +433,8 @@ impl fmt::Display for InvalidTransactionError {
 pub struct CondensedTransaction {
     #[fields(rename = "Summary")]
     pub summary: String,
-    #[fields(rename = "Line Item")]
-    pub line_item: String,
+    #[fields(rename = "Line Item", with = "crate::display::allocations")]
+    pub line_items: Vec<Allocation>,
     #[fields(rename = "Date", with = "crate::display::date")]
     pub date: DateTime<FixedOffset>,
     #[fields(rename = "From Account", with = "table_iter::display::option")]
@@ -189,96 +446,92 @@ pub struct CondensedTransaction {
     #[fields(skip)]
     pub periodic_budget: Option<i32>,
     #[fields(skip)]
+    pub one_time_budget: Option<i32>,
+    #[fields(skip)]
     pub starting_id: Option<i32>,
     #[fields(skip)]
     pub completing_id: Option<i32>,
     #[fields(skip)]
     pub transaction_type: TransactionType,
+    // The id of the line in the source this was imported from. If present,
+    // each leg is given a fingerprint.
+    #[fields(skip)]
+    pub source_id: Option<String>,
+    #[fields(skip)]
+    pub payee: Option<i32>,
+}
+
+impl CondensedTransaction {
+    // The account and amount of each leg that the transaction becomes.
+    pub fn legs(&self) -> Vec<(&str, i64)> {
+        let amount: i64 = self.amount.into();
+        match (&self.from_account, &self.to_account) {
+            (Some(from), Some(to)) => vec![(from, -amount), (to, amount)],
+            (Some(account), None) | (None, Some(account)) => {
+                vec![(account, amount)]
+            }
+            (None, None) => Vec::new(),
+        }
+    }
 }
 
 impl TryInto<NewTransaction> for CondensedTransaction {
-    type Error = InvalidTransactionError;
+    type Error = ValidationError;
     fn try_into(self) -> Result<NewTransaction, Self::Error> {
+        let budgeted =
+            self.periodic_budget.is_some() || self.one_time_budget.is_some();
+        let metadata = TransactionMetadata {
+            periodic_budget: self.periodic_budget,
+            one_time_budget: self.one_time_budget,
+            allocations: self.line_items,
+        };
         let metadata = match self.transaction_type {
+            // A real transaction which hasn't been categorized yet.
             TransactionType::Real => TransactionTypedMetadata::Real(
-                self.periodic_budget.map(|budget| TransactionMetadata {
-                    periodic_budget: budget,
-                    line_item: self.line_item,
-                }),
+                (budgeted && !metadata.allocations.is_empty())
+                    .then_some(metadata),
             ),
+            TransactionType::Planned if budgeted => {
+                TransactionTypedMetadata::Planned(metadata)
+            }
             TransactionType::Planned => {
-                TransactionTypedMetadata::Planned(TransactionMetadata {
-                    line_item: self.line_item,
-                    periodic_budget: self.periodic_budget.ok_or(
-                        InvalidTransactionError::new(
-                            "No periodic budget for planned transaction",
-                        ),
-                    )?,
-                })
+                return Err(ValidationError::new(vec![Violation::NoBudget]))
             }
         };
 
         let amount: i64 = self.amount.into();
-        if self.from_account.is_some() && self.to_account.is_some() {
-            Ok(NewTransaction {
-                metadata,
-                series: NewTransactionSeries::Transfer {
-                    starting: NewTransactionData {
-                        summary: self.summary.clone(),
-                        amount: -1 * amount,
-                        account: self.from_account.ok_or(
-                            InvalidTransactionError::new(
-                                "from_account not specified",
-                            ),
-                        )?,
-                        date: self.date.into(),
-                    },
-
-                    completing: NewTransactionData {
-                        summary: self.summary,
-                        amount,
-                        account: self.to_account.ok_or(
-                            InvalidTransactionError::new(
-                                "to_account not specified",
-                            ),
-                        )?,
-                        date: self.date.into(),
-                    },
-                },
-            })
-        } else if self.from_account.is_some() {
-            Ok(NewTransaction {
-                metadata,
-                series: NewTransactionSeries::Single(NewTransactionData {
-                    summary: self.summary,
+        let leg = |account: String, amount: i64| NewTransactionData {
+            fingerprint: self.source_id.as_ref().map(|source_id| {
+                fingerprint(
+                    &account,
+                    self.date.date_naive(),
                     amount,
-                    account: self.from_account.ok_or(
-                        InvalidTransactionError::new(
-                            "from_account not specified",
-                        ),
-                    )?,
-                    date: self.date.into(),
-                }),
-            })
-        } else if self.to_account.is_some() {
-            Ok(NewTransaction {
-                metadata,
-                series: NewTransactionSeries::Single(NewTransactionData {
-                    summary: self.summary,
-                    amount,
-                    account: self.to_account.ok_or(
-                        InvalidTransactionError::new(
-                            "to_account not specified",
-                        ),
-                    )?,
-                    date: self.date.into(),
-                }),
-            })
-        } else {
-            Err(InvalidTransactionError::new(
-                "Neither from_account nor to_account specified",
-            ))
-        }
+                    &self.summary,
+                    source_id,
+                )
+            }),
+            summary: self.summary.clone(),
+            amount,
+            account,
+            payee: self.payee,
+            date: self.date.into(),
+        };
+        let series = match (self.from_account, self.to_account) {
+            (Some(from), Some(to)) => NewTransactionSeries::Linked(vec![
+                leg(from, -amount),
+                leg(to, amount),
+            ]),
+            (Some(account), None) | (None, Some(account)) => {
+                NewTransactionSeries::Single(leg(account, amount))
+            }
+            (None, None) => {
+                return Err(ValidationError::new(vec![Violation::NoAccount]))
+            }
+        };
+
+        let transaction = NewTransaction { metadata, series };
+        transaction.validate()?;
+        Ok(transaction)
     }
 }
 
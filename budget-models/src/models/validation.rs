@@ -0,0 +1,540 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            validation.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Validation of transactions before they are persisted.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+use super::{Account, NewTransaction, OneTimeBudget, PeriodicBudget};
+
+///////////////////////////////////////////////////////////////////////////////
+// Violation
+//  A single reason that a transaction, or a rule for categorizing
+//  transactions, can't be persisted.
+////
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "violation", rename_all = "snake_case")]
+pub enum Violation {
+    NoAccount,
+    NoBudget,
+    Unallocated,
+    AllocationMismatch {
+        allocated: i64,
+        amount: i64,
+    },
+    TooFewLegs {
+        legs: usize,
+    },
+    UnbalancedSeries {
+        balance: i64,
+    },
+    MissingAccount {
+        account: String,
+    },
+    AccountNotOpen {
+        account: String,
+        date: DateTime<Utc>,
+    },
+    MissingLineItem {
+        line_item: String,
+    },
+    MissingPeriodicBudget {
+        budget: i32,
+    },
+    OutsidePeriodicBudget {
+        budget: i32,
+        date: DateTime<Utc>,
+    },
+    MissingOneTimeBudget {
+        budget: i32,
+    },
+    OutsideOneTimeBudget {
+        budget: i32,
+        date: DateTime<Utc>,
+    },
+    InvalidPattern {
+        pattern: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let date = |date: &DateTime<Utc>| date.format("%d %b %Y").to_string();
+        match self {
+            Self::NoAccount => {
+                write!(f, "Neither from_account nor to_account specified")
+            }
+            Self::NoBudget => {
+                write!(f, "Transaction is not associated with any budget")
+            }
+            Self::Unallocated => {
+                write!(f, "Transaction is not allocated to any line item")
+            }
+            // The currency isn't known here, so amounts are reported in the
+            // minor unit, e.g. cents.
+            Self::AllocationMismatch { allocated, amount } => write!(
+                f,
+                "Allocations sum to {} minor units, but the transaction \
+                 amount is {}",
+                allocated, amount
+            ),
+            Self::TooFewLegs { legs } => write!(
+                f,
+                "A linked series must have at least two legs, not {}",
+                legs
+            ),
+            Self::UnbalancedSeries { balance } => write!(
+                f,
+                "Legs of a linked series must sum to zero, not {} minor \
+                 units",
+                balance
+            ),
+            Self::MissingAccount { account } => {
+                write!(f, "Account {} does not exist", account)
+            }
+            Self::AccountNotOpen { account, date: d } => {
+                write!(f, "Account {} is not open on {}", account, date(d))
+            }
+            Self::MissingLineItem { line_item } => {
+                write!(f, "Line item {} does not exist", line_item)
+            }
+            Self::MissingPeriodicBudget { budget } => {
+                write!(f, "Periodic budget {} does not exist", budget)
+            }
+            Self::OutsidePeriodicBudget { budget, date: d } => write!(
+                f,
+                "{} is outside of periodic budget {}",
+                date(d),
+                budget
+            ),
+            Self::MissingOneTimeBudget { budget } => {
+                write!(f, "One-time budget {} does not exist", budget)
+            }
+            Self::OutsideOneTimeBudget { budget, date: d } => write!(
+                f,
+                "{} is outside of one-time budget {}",
+                date(d),
+                budget
+            ),
+            Self::InvalidPattern { pattern, reason } => {
+                write!(f, "Invalid pattern {:?}: {}", pattern, reason)
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ValidationError
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ValidationError(Vec<Violation>);
+
+impl ValidationError {
+    pub fn new(violations: Vec<Violation>) -> Self {
+        Self(violations)
+    }
+
+    // Ok if there are no violations.
+    pub fn check(violations: Vec<Violation>) -> Result<(), Self> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Self(violations))
+        }
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.0
+    }
+}
+
+impl Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let violations = self
+            .0
+            .iter()
+            .map(|violation| violation.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "{}", violations.join("; "))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ValidationContext
+//  What exists in the database, so that references from a transaction to
+//  accounts, line items and budgets can be checked before it's persisted.
+////
+
+#[derive(Clone, Debug, Default)]
+pub struct ValidationContext {
+    accounts: HashMap<String, Account>,
+    line_items: HashSet<String>,
+    periodic_budgets: HashMap<i32, PeriodicBudget>,
+    one_time_budgets: HashMap<i32, OneTimeBudget>,
+}
+
+impl ValidationContext {
+    pub fn new(
+        accounts: Vec<Account>,
+        line_items: Vec<String>,
+        periodic_budgets: Vec<PeriodicBudget>,
+        one_time_budgets: Vec<OneTimeBudget>,
+    ) -> Self {
+        Self {
+            accounts: accounts
+                .into_iter()
+                .map(|account| (account.name.clone(), account))
+                .collect(),
+            line_items: line_items.into_iter().collect(),
+            periodic_budgets: periodic_budgets
+                .into_iter()
+                .map(|budget| (budget.id, budget))
+                .collect(),
+            one_time_budgets: one_time_budgets
+                .into_iter()
+                .map(|budget| (budget.id, budget))
+                .collect(),
+        }
+    }
+
+    // All violations of the transaction, including those found by
+    // NewTransaction::violations().
+    pub fn violations(&self, transaction: &NewTransaction) -> Vec<Violation> {
+        let mut violations = transaction.violations();
+        let legs = transaction.series.legs();
+        for leg in &legs {
+            match self.accounts.get(&leg.account) {
+                None => violations.push(Violation::MissingAccount {
+                    account: leg.account.clone(),
+                }),
+                Some(account)
+                    if leg.date < account.date_opened
+                        || account
+                            .date_closed
+                            .iter()
+                            .any(|closed| leg.date > *closed) =>
+                {
+                    violations.push(Violation::AccountNotOpen {
+                        account: leg.account.clone(),
+                        date: leg.date,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        let metadata = match transaction.metadata.metadata() {
+            Some(metadata) => metadata,
+            None => return violations,
+        };
+
+        for allocation in &metadata.allocations {
+            if !self.line_items.contains(&allocation.line_item) {
+                violations.push(Violation::MissingLineItem {
+                    line_item: allocation.line_item.clone(),
+                });
+            }
+        }
+
+        if let Some(id) = metadata.periodic_budget {
+            match self.periodic_budgets.get(&id) {
+                None => violations
+                    .push(Violation::MissingPeriodicBudget { budget: id }),
+                Some(budget) => {
+                    for leg in &legs {
+                        if leg.date < budget.start_date
+                            || leg.date > budget.end_date
+                        {
+                            violations.push(
+                                Violation::OutsidePeriodicBudget {
+                                    budget: id,
+                                    date: leg.date,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(id) = metadata.one_time_budget {
+            match self.one_time_budgets.get(&id) {
+                None => violations
+                    .push(Violation::MissingOneTimeBudget { budget: id }),
+                Some(budget) => {
+                    for leg in &legs {
+                        if !budget.contains(&leg.date) {
+                            violations.push(Violation::OutsideOneTimeBudget {
+                                budget: id,
+                                date: leg.date,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+
+    pub fn validate(
+        &self,
+        transaction: &NewTransaction,
+    ) -> Result<(), ValidationError> {
+        ValidationError::check(self.violations(transaction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        AccountType, Allocation, NewTransactionData, NewTransactionSeries,
+        OneTimeBudgetStatus, TransactionMetadata, TransactionTypedMetadata,
+    };
+    use crate::Currency;
+    use chrono::TimeZone;
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+    }
+
+    fn context() -> ValidationContext {
+        let checking = Account {
+            name: "Checking".to_string(),
+            account_type: AccountType::Checking,
+            currency: Currency::Usd,
+            date_opened: date(2024, 1, 1),
+            date_closed: Some(date(2024, 12, 31)),
+        };
+        let january = PeriodicBudget {
+            id: 1,
+            start_date: date(2024, 1, 1),
+            end_date: date(2024, 1, 31),
+        };
+        let vacation = OneTimeBudget {
+            id: 2,
+            description: "Vacation".to_string(),
+            target: 100000,
+            account: None,
+            start_date: Some(date(2024, 1, 1)),
+            end_date: Some(date(2024, 1, 20)),
+            status: OneTimeBudgetStatus::Active,
+        };
+        ValidationContext::new(
+            vec![checking],
+            vec!["Groceries".to_string()],
+            vec![january],
+            vec![vacation],
+        )
+    }
+
+    fn leg(
+        account: &str,
+        amount: i64,
+        date: DateTime<Utc>,
+    ) -> NewTransactionData {
+        NewTransactionData {
+            summary: "Test".to_string(),
+            account: account.to_string(),
+            amount,
+            date,
+            ..Default::default()
+        }
+    }
+
+    fn metadata(
+        periodic_budget: Option<i32>,
+        one_time_budget: Option<i32>,
+        line_item: &str,
+        amount: i64,
+    ) -> TransactionMetadata {
+        TransactionMetadata {
+            periodic_budget,
+            one_time_budget,
+            allocations: vec![Allocation {
+                line_item: line_item.to_string(),
+                amount,
+            }],
+        }
+    }
+
+    #[test]
+    fn valid_transaction() {
+        let transaction = NewTransaction {
+            metadata: TransactionTypedMetadata::Planned(metadata(
+                Some(1),
+                Some(2),
+                "Groceries",
+                -5000,
+            )),
+            series: NewTransactionSeries::Single(leg(
+                "Checking",
+                -5000,
+                date(2024, 1, 20),
+            )),
+        };
+        assert_eq!(Ok(()), context().validate(&transaction));
+    }
+
+    #[test]
+    fn accounts_must_exist_and_be_open() {
+        let transaction = NewTransaction {
+            metadata: TransactionTypedMetadata::Real(None),
+            series: NewTransactionSeries::Linked(vec![
+                leg("Checking", -100, date(2023, 12, 31)),
+                leg("Savings", 100, date(2023, 12, 31)),
+            ]),
+        };
+        assert_eq!(
+            vec![
+                Violation::AccountNotOpen {
+                    account: "Checking".to_string(),
+                    date: date(2023, 12, 31),
+                },
+                Violation::MissingAccount {
+                    account: "Savings".to_string(),
+                },
+            ],
+            context().violations(&transaction)
+        );
+
+        let transaction = NewTransaction {
+            metadata: TransactionTypedMetadata::Real(None),
+            series: NewTransactionSeries::Single(leg(
+                "Checking",
+                -100,
+                date(2025, 1, 1),
+            )),
+        };
+        assert_eq!(
+            vec![Violation::AccountNotOpen {
+                account: "Checking".to_string(),
+                date: date(2025, 1, 1),
+            }],
+            context().violations(&transaction)
+        );
+    }
+
+    #[test]
+    fn references_must_exist() {
+        let transaction = NewTransaction {
+            metadata: TransactionTypedMetadata::Real(Some(metadata(
+                Some(9),
+                Some(8),
+                "Rent",
+                -100,
+            ))),
+            series: NewTransactionSeries::Single(leg(
+                "Checking",
+                -100,
+                date(2024, 1, 15),
+            )),
+        };
+        assert_eq!(
+            vec![
+                Violation::MissingLineItem {
+                    line_item: "Rent".to_string(),
+                },
+                Violation::MissingPeriodicBudget { budget: 9 },
+                Violation::MissingOneTimeBudget { budget: 8 },
+            ],
+            context().violations(&transaction)
+        );
+    }
+
+    #[test]
+    fn legs_must_fall_within_the_budgets() {
+        let transaction = NewTransaction {
+            metadata: TransactionTypedMetadata::Planned(metadata(
+                Some(1),
+                Some(2),
+                "Groceries",
+                -100,
+            )),
+            series: NewTransactionSeries::Single(leg(
+                "Checking",
+                -100,
+                date(2024, 2, 1),
+            )),
+        };
+        assert_eq!(
+            vec![
+                Violation::OutsidePeriodicBudget {
+                    budget: 1,
+                    date: date(2024, 2, 1),
+                },
+                Violation::OutsideOneTimeBudget {
+                    budget: 2,
+                    date: date(2024, 2, 1),
+                },
+            ],
+            context().violations(&transaction)
+        );
+    }
+
+    #[test]
+    fn amounts_must_agree() {
+        let transaction = NewTransaction {
+            metadata: TransactionTypedMetadata::Planned(metadata(
+                Some(1),
+                None,
+                "Groceries",
+                100,
+            )),
+            series: NewTransactionSeries::Linked(vec![
+                leg("Checking", -100, date(2024, 1, 15)),
+                leg("Checking", 90, date(2024, 1, 15)),
+            ]),
+        };
+        let error = context().validate(&transaction).unwrap_err();
+        assert_eq!(
+            &[
+                Violation::UnbalancedSeries { balance: -10 },
+                Violation::AllocationMismatch {
+                    allocated: 100,
+                    amount: 90,
+                },
+            ],
+            error.violations()
+        );
+        assert_eq!(
+            "Legs of a linked series must sum to zero, not -10 minor units; \
+             Allocations sum to 100 minor units, but the transaction amount \
+             is 90",
+            error.to_string()
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         07/08/2022
 //
-// LAST EDITED:     11/19/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,56 +25,563 @@
 ////
 
 use serde::{Deserialize, Serialize};
+use std::error::Error;
 use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+use crate::currency::Currency;
 
 ///////////////////////////////////////////////////////////////////////////////
 // Money
-//  This type represents a value of money.
+//  This type represents a value of money, as an integral number of the minor
+//  unit of its currency (e.g. cents). Arithmetic on this type is exact, so it
+//  should be preferred over floating point values anywhere that amounts are
+//  summed or compared.
+//
+//  An amount is always built along with its currency, e.g. amounts of the
+//  ledger take the currency of their account; there's deliberately no
+//  conversion from a bare integer.
+//
+//  Amounts in different currencies can't be added or subtracted; the
+//  operators panic in this case, and the checked variants return None. The
+//  operators are only for amounts known to share a currency, e.g. amounts of
+//  the same account, or amounts already converted. As a convenience, a zero
+//  amount takes on the currency of the other operand, so that accumulators
+//  may start from Money::default().
 ////
 
 #[derive(
-    Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Deserialize,
+    Serialize,
 )]
-pub struct Money(i64);
+pub struct Money {
+    amount: i64,
+    currency: Currency,
+}
 
 impl Money {
-    pub fn add(&mut self, value: Money) {
-        self.0 += value.0;
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
+    pub fn abs(self) -> Money {
+        Money::new(self.amount.abs(), self.currency)
+    }
+
+    // The currency of the result of an operation on self and value, if the
+    // operation is allowed.
+    fn common_currency(&self, value: &Money) -> Option<Currency> {
+        if self.currency == value.currency || value.amount == 0 {
+            Some(self.currency)
+        } else if self.amount == 0 {
+            Some(value.currency)
+        } else {
+            None
+        }
+    }
+
+    pub fn checked_add(self, value: Money) -> Option<Money> {
+        let currency = self.common_currency(&value)?;
+        let amount = self.amount.checked_add(value.amount)?;
+        Some(Money::new(amount, currency))
+    }
+
+    pub fn checked_sub(self, value: Money) -> Option<Money> {
+        let currency = self.common_currency(&value)?;
+        let amount = self.amount.checked_sub(value.amount)?;
+        Some(Money::new(amount, currency))
+    }
+
+    // Sum the amounts, or None if they can't be combined.
+    pub fn checked_sum<I>(values: I) -> Option<Money>
+    where
+        I: IntoIterator<Item = Money>,
+    {
+        values
+            .into_iter()
+            .try_fold(Money::default(), |total, value| {
+                total.checked_add(value)
+            })
+    }
+
+    pub fn checked_neg(self) -> Option<Money> {
+        let amount = self.amount.checked_neg()?;
+        Some(Money::new(amount, self.currency))
+    }
+
+    pub fn checked_mul(self, value: i64) -> Option<Money> {
+        let amount = self.amount.checked_mul(value)?;
+        Some(Money::new(amount, self.currency))
+    }
+
+    // Multiply this amount by the fraction numerator/denominator, rounding any
+    // fractional cent to the nearest even cent (banker's rounding). Returns
+    // None if the denominator is zero or the result does not fit.
+    pub fn scale(self, numerator: i64, denominator: i64) -> Option<Money> {
+        self.scale_wide(numerator as i128, denominator as i128)
+    }
+
+    fn scale_wide(self, numerator: i128, denominator: i128) -> Option<Money> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let value = round_half_even(
+            (self.amount as i128).checked_mul(numerator)?,
+            denominator,
+        );
+        let amount = i64::try_from(value).ok()?;
+        Some(Money::new(amount, self.currency))
+    }
+
+    // Convert this amount to another currency, where one unit of this
+    // currency is worth numerator/denominator units of the other currency.
+    // The result is rounded to the minor unit of the new currency using
+    // banker's rounding.
+    pub fn convert(
+        self,
+        currency: Currency,
+        numerator: i64,
+        denominator: i64,
+    ) -> Option<Money> {
+        let from = self.currency.decimal_places();
+        let to = currency.decimal_places();
+        let (mut numerator, mut denominator) =
+            (numerator as i128, denominator as i128);
+        if to > from {
+            numerator = numerator.checked_mul(10i128.pow(to - from))?;
+        } else {
+            denominator = denominator.checked_mul(10i128.pow(from - to))?;
+        }
+
+        let converted = self.scale_wide(numerator, denominator)?;
+        Some(Money::new(converted.amount, currency))
+    }
+
+    // Allocate this amount into parts proportional to the ratios, such that
+    // the parts always sum to exactly this amount; see allocate() for how
+    // the leftover cents are handed out. For example, $100.00 allocated as
+    // [1, 1, 1] is [33.34, 33.33, 33.33]. Returns None if there are no
+    // ratios, or if the ratios sum to zero.
+    pub fn allocate(self, ratios: &[u64]) -> Option<Vec<Money>> {
+        Some(
+            allocate(self.amount, ratios)?
+                .into_iter()
+                .map(|share| Money::new(share, self.currency))
+                .collect(),
+        )
+    }
+
+    // Split this amount into the given number of (nearly) equal parts.
+    pub fn split(self, parts: usize) -> Option<Vec<Money>> {
+        self.allocate(&vec![1; parts])
+    }
+}
+
+// Allocate an amount of the minor unit into parts proportional to the
+// ratios. Each part is first rounded towards zero, and the leftover units are
+// then handed out one at a time to the parts with the largest remainders
+// (earlier parts win ties).
+pub(crate) fn allocate(amount: i64, ratios: &[u64]) -> Option<Vec<i64>> {
+    let total: i128 = ratios.iter().map(|r| *r as i128).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let magnitude = (amount as i128).abs();
+    let mut parts = ratios
+        .iter()
+        .map(|ratio| {
+            let exact = magnitude * *ratio as i128;
+            (exact / total, exact % total)
+        })
+        .collect::<Vec<(i128, i128)>>();
+
+    let allocated: i128 = parts.iter().map(|(share, _)| share).sum();
+    let mut order = (0..parts.len()).collect::<Vec<usize>>();
+    order.sort_by(|a, b| parts[*b].1.cmp(&parts[*a].1));
+    for index in order.into_iter().take((magnitude - allocated) as usize) {
+        parts[index].0 += 1;
+    }
+
+    let sign = amount.signum() as i128;
+    Some(
+        parts
+            .into_iter()
+            .map(|(share, _)| (sign * share) as i64)
+            .collect(),
+    )
+}
+
+// Divide, rounding to the nearest integer, and rounding ties to even.
+fn round_half_even(numerator: i128, denominator: i128) -> i128 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+
+    let quotient = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    match (2 * remainder).cmp(&denominator) {
+        std::cmp::Ordering::Less => quotient,
+        std::cmp::Ordering::Greater => quotient + 1,
+        std::cmp::Ordering::Equal => quotient + (quotient & 1),
+    }
+}
+
+fn currency_mismatch(left: &Money, right: &Money) -> ! {
+    panic!(
+        "cannot combine amounts in {} and {}",
+        left.currency, right.currency
+    )
+}
+
+impl Add for Money {
+    type Output = Money;
+    fn add(self, value: Money) -> Money {
+        let currency = self
+            .common_currency(&value)
+            .unwrap_or_else(|| currency_mismatch(&self, &value));
+        Money::new(self.amount + value.amount, currency)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, value: Money) {
+        *self = *self + value;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+    fn sub(self, value: Money) -> Money {
+        let currency = self
+            .common_currency(&value)
+            .unwrap_or_else(|| currency_mismatch(&self, &value));
+        Money::new(self.amount - value.amount, currency)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, value: Money) {
+        *self = *self - value;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+    fn neg(self) -> Money {
+        Money::new(-self.amount, self.currency)
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Money;
+    fn mul(self, value: i64) -> Money {
+        Money::new(self.amount * value, self.currency)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Self {
+        iter.fold(Money::default(), |total, value| total + value)
     }
+}
 
-    pub fn subtract(&mut self, value: Money) {
-        self.0 -= value.0;
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Self {
+        iter.fold(Money::default(), |total, value| total + *value)
     }
 }
 
 impl From<f64> for Money {
     fn from(value: f64) -> Self {
-        Self((value * 100.0) as i64)
+        Self::new((value * 100.0).round() as i64, Currency::Usd)
     }
 }
 
 impl Into<f64> for Money {
     fn into(self) -> f64 {
-        self.0 as f64 / 100.0
-    }
-}
-
-impl From<i64> for Money {
-    fn from(value: i64) -> Self {
-        Self(value)
+        self.amount as f64 / 10i64.pow(self.currency.decimal_places()) as f64
     }
 }
 
 impl Into<i64> for Money {
     fn into(self) -> i64 {
-        self.0
+        self.amount
     }
 }
 
 impl fmt::Display for Money {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let amount: f64 = (*self).into();
-        write!(f, "{:.2}", amount)
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let symbol = self.currency.symbol();
+        let magnitude = self.amount.unsigned_abs();
+        let places = self.currency.decimal_places();
+        if places == 0 {
+            return write!(f, "{}{}{}", sign, symbol, magnitude);
+        }
+
+        let unit = 10u64.pow(places);
+        write!(
+            f,
+            "{}{}{}.{:0width$}",
+            sign,
+            symbol,
+            magnitude / unit,
+            magnitude % unit,
+            width = places as usize
+        )
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ParseMoneyError
+////
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParseMoneyError(String);
+impl ParseMoneyError {
+    pub fn new<S: AsRef<str>>(value: S) -> Self {
+        Self(value.as_ref().to_string())
+    }
+}
+
+impl Error for ParseMoneyError {}
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid monetary amount: {:?}", &self.0)
+    }
+}
+
+// Remove a leading '+' or '-', returning whether the sign was negative.
+fn strip_sign(text: &str) -> (Option<bool>, &str) {
+    if let Some(rest) = text.strip_prefix('-') {
+        (Some(true), rest)
+    } else if let Some(rest) = text.strip_prefix('+') {
+        (Some(false), rest)
+    } else {
+        (None, text)
+    }
+}
+
+// Remove a leading currency symbol. Longer symbols are tried first, so that
+// "CA$" is not mistaken for "$".
+fn strip_symbol(text: &str) -> (Option<Currency>, &str) {
+    let mut currencies = Currency::iter().collect::<Vec<Currency>>();
+    currencies.sort_by_key(|c| std::cmp::Reverse(c.symbol().len()));
+    currencies
+        .into_iter()
+        .find_map(|c| text.strip_prefix(c.symbol()).map(|t| (Some(c), t)))
+        .unwrap_or((None, text))
+}
+
+// Remove an ISO 4217 code separated by whitespace from either end.
+fn strip_code(text: &str) -> (Option<Currency>, &str) {
+    if let Some((code, rest)) = text.split_once(char::is_whitespace) {
+        if let Ok(currency) = code.parse::<Currency>() {
+            return (Some(currency), rest.trim_start());
+        }
+    }
+
+    if let Some((rest, code)) = text.rsplit_once(char::is_whitespace) {
+        if let Ok(currency) = code.parse::<Currency>() {
+            return (Some(currency), rest.trim_end());
+        }
+    }
+
+    (None, text)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FromStr
+//  Parses amounts in the forms commonly found in bank statements, e.g.
+//  "1,234.56", "-$12.00", "$-12", "+3.5", "(45.10)" (accounting notation for
+//  a negative amount), "CA$5.00" or "5.00 EUR". Amounts without a
+//  currency symbol or code are taken to be in the default currency. Parsing
+//  is exact, and never goes through a float.
+////
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseMoneyError::new(value);
+        let (code, mut text) = strip_code(value.trim());
+        let mut negative = false;
+
+        if let Some(inner) =
+            text.strip_prefix('(').and_then(|t| t.strip_suffix(')'))
+        {
+            negative = true;
+            text = inner.trim();
+        }
+
+        // Accept the sign on either side of the currency symbol.
+        let (mut sign, rest) = strip_sign(text);
+        let (symbol, rest) = strip_symbol(rest);
+        text = rest;
+        if sign.is_none() {
+            (sign, text) = strip_sign(text);
+        }
+        match sign {
+            Some(_) if negative => return Err(error()),
+            Some(minus) => negative = minus,
+            None => (),
+        }
+
+        let currency = match (code, symbol) {
+            (Some(code), Some(symbol)) if code != symbol => {
+                return Err(error())
+            }
+            (code, symbol) => code.or(symbol).unwrap_or_default(),
+        };
+        let places = currency.decimal_places() as usize;
+
+        let (whole, fraction) = match text.split_once('.') {
+            Some((whole, fraction)) => (whole, fraction),
+            None => (text, ""),
+        };
+        if (whole.is_empty() && fraction.is_empty())
+            || (places == 0 && text.contains('.'))
+            || fraction.len() > places
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(error());
+        }
+
+        // Thousands separators are optional, but must be well-formed.
+        let groups = whole.split(',').collect::<Vec<&str>>();
+        let well_formed = groups.iter().enumerate().all(|(i, group)| {
+            group.bytes().all(|b| b.is_ascii_digit())
+                && match i {
+                    0 if groups.len() > 1 => (1..=3).contains(&group.len()),
+                    0 => true,
+                    _ => group.len() == 3,
+                }
+        });
+        if !well_formed {
+            return Err(error());
+        }
+
+        let mut amount: i64 = 0;
+        let digits = groups
+            .into_iter()
+            .flat_map(|group| group.bytes())
+            .chain(fraction.bytes())
+            .chain((fraction.len()..places).map(|_| b'0'));
+        for digit in digits {
+            amount = amount
+                .checked_mul(10)
+                .and_then(|a| a.checked_add((digit - b'0') as i64))
+                .ok_or_else(error)?;
+        }
+
+        Ok(Money::new(
+            if negative { -amount } else { amount },
+            currency,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(amount: i64) -> Money {
+        Money::new(amount, Currency::Usd)
+    }
+
+    #[test]
+    fn parse_statement_forms() {
+        assert_eq!(Ok(usd(123456)), "1,234.56".parse());
+        assert_eq!(Ok(usd(-1200)), "-$12.00".parse());
+        assert_eq!(Ok(usd(-1200)), "$-12".parse());
+        assert_eq!(Ok(usd(-4510)), "(45.10)".parse());
+        assert_eq!(Ok(usd(350)), "+3.5".parse());
+        assert_eq!(
+            Ok(Money::new(500, Currency::Cad)),
+            "CA$5.00".parse::<Money>()
+        );
+        assert_eq!(
+            Ok(Money::new(500, Currency::Eur)),
+            "5.00 EUR".parse::<Money>()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_separators() {
+        for text in ["1,23.4", "1.2.3", "1234,567", ",123", "12.345"] {
+            assert!(text.parse::<Money>().is_err(), "{:?} parsed", text);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_conflicting_signs_and_currencies() {
+        for text in ["(-45.10)", "$5.00 EUR", "\u{a5}1.5"] {
+            assert!(text.parse::<Money>().is_err(), "{:?} parsed", text);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_overflow() {
+        assert_eq!(Ok(usd(i64::MAX)), "92,233,720,368,547,758.07".parse());
+        assert!("92,233,720,368,547,758.08".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn allocate_hands_out_the_remainder() {
+        let parts = usd(10000).split(3).unwrap();
+        assert_eq!(vec![usd(3334), usd(3333), usd(3333)], parts);
+        let parts = usd(-10000).split(3).unwrap();
+        assert_eq!(vec![usd(-3334), usd(-3333), usd(-3333)], parts);
+        let parts = usd(100).allocate(&[1, 2]).unwrap();
+        assert_eq!(vec![usd(33), usd(67)], parts);
+        assert_eq!(None, usd(100).allocate(&[]));
+        assert_eq!(None, usd(100).allocate(&[0, 0]));
+    }
+
+    #[test]
+    fn scale_rounds_half_to_even() {
+        assert_eq!(Some(usd(2)), usd(5).scale(1, 2));
+        assert_eq!(Some(usd(8)), usd(15).scale(1, 2));
+        assert_eq!(Some(usd(-2)), usd(-5).scale(1, 2));
+        assert_eq!(Some(usd(-8)), usd(-15).scale(1, 2));
+        assert_eq!(Some(usd(3)), usd(5).scale(2, 3));
+        assert_eq!(None, usd(5).scale(1, 0));
+        assert_eq!(None, usd(i64::MAX).scale(2, 1));
     }
 }
 
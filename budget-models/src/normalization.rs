@@ -0,0 +1,118 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            normalization.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Normalization of transaction descriptions to payees, by
+//                  the aliases of the payees.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use regex::{Regex, RegexBuilder};
+
+use crate::models::{
+    CondensedTransaction, NewPayee, NewPayeeAlias, Payee, Violation,
+};
+
+// Aliases are matched against descriptions ignoring case, since banks aren't
+// consistent about it.
+fn compile(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).case_insensitive(true).build()
+}
+
+fn pattern_violations(pattern: &str) -> Vec<Violation> {
+    match compile(pattern) {
+        Ok(_) => Vec::new(),
+        Err(e) => vec![Violation::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: e.to_string(),
+        }],
+    }
+}
+
+impl NewPayee {
+    pub fn violations(&self) -> Vec<Violation> {
+        self.aliases
+            .iter()
+            .flat_map(|pattern| pattern_violations(pattern))
+            .collect()
+    }
+}
+
+impl NewPayeeAlias {
+    pub fn violations(&self) -> Vec<Violation> {
+        pattern_violations(&self.pattern)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Payees
+// The aliases of every payee, ready to be matched against transactions.
+// Aliases are tried in the order that they were created.
+////
+
+pub struct Payees {
+    payees: Vec<Payee>,
+    // Each alias, with the index of its payee.
+    aliases: Vec<(Regex, usize)>,
+}
+
+impl Payees {
+    // Fails if any alias isn't a valid regular expression.
+    pub fn new(payees: Vec<Payee>) -> Result<Self, regex::Error> {
+        let mut aliases = payees
+            .iter()
+            .enumerate()
+            .flat_map(|(index, payee)| {
+                payee.aliases.iter().map(move |alias| (alias, index))
+            })
+            .collect::<Vec<_>>();
+        aliases.sort_by_key(|(alias, _)| alias.id);
+        let aliases = aliases
+            .into_iter()
+            .map(|(alias, index)| Ok((compile(&alias.pattern)?, index)))
+            .collect::<Result<Vec<(Regex, usize)>, regex::Error>>()?;
+        Ok(Self { payees, aliases })
+    }
+
+    // The payee of the first alias which matches the description.
+    pub fn find(&self, summary: &str) -> Option<&Payee> {
+        self.aliases
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(summary))
+            .map(|(_, index)| &self.payees[*index])
+    }
+
+    // Assign the payee of a transaction which doesn't have one yet. Returns
+    // the payee which was assigned, if any.
+    pub fn assign(
+        &self,
+        transaction: &mut CondensedTransaction,
+    ) -> Option<&Payee> {
+        if transaction.payee.is_some() {
+            return None;
+        }
+        let payee = self.find(&transaction.summary)?;
+        transaction.payee = Some(payee.id);
+        Some(payee)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,237 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            rules.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Engine for assigning line items to transactions by rule.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use regex::Regex;
+
+use crate::models::{
+    Allocation, CondensedTransaction, LineItemRule, NewLineItemRule, Violation,
+};
+
+impl NewLineItemRule {
+    pub fn violations(&self) -> Vec<Violation> {
+        match &self.pattern {
+            Some(pattern) => match Regex::new(pattern) {
+                Ok(_) => Vec::new(),
+                Err(e) => vec![Violation::InvalidPattern {
+                    pattern: pattern.clone(),
+                    reason: e.to_string(),
+                }],
+            },
+            None => Vec::new(),
+        }
+    }
+}
+
+struct CompiledRule {
+    rule: LineItemRule,
+    pattern: Option<Regex>,
+}
+
+impl CompiledRule {
+    fn matches(&self, summary: &str, account: &str, amount: i64) -> bool {
+        let rule = &self.rule;
+        rule.account.iter().all(|a| a == account)
+            && rule.amount.iter().all(|a| *a == amount)
+            && rule.contains.iter().all(|text| {
+                summary.to_lowercase().contains(&text.to_lowercase())
+            })
+            && self.pattern.iter().all(|pattern| pattern.is_match(summary))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LineItemRules
+// A set of rules, ready to be matched against transactions.
+////
+
+pub struct LineItemRules {
+    rules: Vec<CompiledRule>,
+}
+
+impl LineItemRules {
+    // Fails if the pattern of any rule isn't a valid regular expression.
+    pub fn new(mut rules: Vec<LineItemRule>) -> Result<Self, regex::Error> {
+        rules.sort_by_key(|rule| (rule.priority, rule.id));
+        let rules = rules
+            .into_iter()
+            .map(|rule| {
+                let pattern =
+                    rule.pattern.as_deref().map(Regex::new).transpose()?;
+                Ok(CompiledRule { rule, pattern })
+            })
+            .collect::<Result<Vec<CompiledRule>, regex::Error>>()?;
+        Ok(Self { rules })
+    }
+
+    // The first rule which matches any leg of the transaction.
+    pub fn find(
+        &self,
+        transaction: &CondensedTransaction,
+    ) -> Option<&LineItemRule> {
+        let legs = transaction.legs();
+        self.rules
+            .iter()
+            .find(|rule| {
+                legs.iter().any(|(account, amount)| {
+                    rule.matches(&transaction.summary, account, *amount)
+                })
+            })
+            .map(|rule| &rule.rule)
+    }
+
+    // Allocate a transaction which has no line items to the line item of the
+    // first rule that matches it. Transactions which already have line items
+    // are left alone. Returns the rule which was applied, if any.
+    pub fn assign(
+        &self,
+        transaction: &mut CondensedTransaction,
+    ) -> Option<&LineItemRule> {
+        if !transaction.line_items.is_empty() {
+            return None;
+        }
+        let rule = self.find(transaction)?;
+        transaction.line_items = vec![Allocation {
+            line_item: rule.line_item.clone(),
+            amount: transaction.amount.into(),
+        }];
+        Some(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Currency, Money};
+
+    fn rule(id: i32, priority: i32, line_item: &str) -> LineItemRule {
+        LineItemRule {
+            id,
+            priority,
+            line_item: line_item.to_string(),
+            account: None,
+            pattern: None,
+            contains: None,
+            amount: None,
+        }
+    }
+
+    fn purchase(
+        summary: &str,
+        account: &str,
+        amount: i64,
+    ) -> CondensedTransaction {
+        CondensedTransaction {
+            summary: summary.to_string(),
+            from_account: Some(account.to_string()),
+            amount: Money::new(amount, Currency::Usd),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rules_match_in_order_of_priority_then_id() {
+        let contains = |id, priority, line_item| LineItemRule {
+            contains: Some("cafe".to_string()),
+            ..rule(id, priority, line_item)
+        };
+        let rules = LineItemRules::new(vec![
+            contains(1, 2, "Groceries"),
+            contains(3, 1, "Dining"),
+            contains(2, 1, "Coffee"),
+        ])
+        .unwrap();
+        let found = rules.find(&purchase("CAFE LUNA", "Checking", 650));
+        assert_eq!(Some(2), found.map(|rule| rule.id));
+        assert_eq!(None, rules.find(&purchase("Grocer", "Checking", 650)));
+    }
+
+    #[test]
+    fn every_condition_must_match() {
+        let rules = LineItemRules::new(vec![LineItemRule {
+            account: Some("Credit".to_string()),
+            amount: Some(-650),
+            contains: Some("CAFE".to_string()),
+            pattern: Some(r"^Cafe \d+$".to_string()),
+            ..rule(1, 1, "Coffee")
+        }])
+        .unwrap();
+        let matches = |transaction: CondensedTransaction| {
+            rules.find(&transaction).is_some()
+        };
+        assert!(matches(purchase("Cafe 42", "Credit", -650)));
+        assert!(!matches(purchase("Cafe 42", "Checking", -650)));
+        assert!(!matches(purchase("Cafe 42", "Credit", -700)));
+        assert!(!matches(purchase("Bakery 42", "Credit", -650)));
+        assert!(!matches(purchase("Cafe Luna 42", "Credit", -650)));
+
+        // Each leg of a transfer is matched separately.
+        let transfer = CondensedTransaction {
+            to_account: Some("Credit".to_string()),
+            ..purchase("Cafe 42", "Checking", -650)
+        };
+        assert!(matches(transfer));
+    }
+
+    #[test]
+    fn assign_leaves_categorized_transactions_alone() {
+        let rules = LineItemRules::new(vec![rule(1, 1, "Groceries")]).unwrap();
+        let mut transaction = purchase("Grocer", "Checking", 4512);
+        assert_eq!(Some(1), rules.assign(&mut transaction).map(|r| r.id));
+        assert_eq!(
+            vec![Allocation {
+                line_item: "Groceries".to_string(),
+                amount: 4512,
+            }],
+            transaction.line_items
+        );
+
+        transaction.line_items[0].line_item = "Household".to_string();
+        assert_eq!(None, rules.assign(&mut transaction));
+        assert_eq!("Household", transaction.line_items[0].line_item);
+    }
+
+    #[test]
+    fn invalid_patterns_are_rejected() {
+        let invalid = LineItemRule {
+            pattern: Some("Cafe (".to_string()),
+            ..rule(1, 1, "Coffee")
+        };
+        assert!(LineItemRules::new(vec![invalid]).is_err());
+
+        let new = NewLineItemRule {
+            pattern: Some("Cafe (".to_string()),
+            ..Default::default()
+        };
+        let violations = new.violations();
+        assert!(matches!(
+            violations.as_slice(),
+            [Violation::InvalidPattern { pattern, .. }] if pattern == "Cafe ("
+        ));
+        assert!(NewLineItemRule::default().violations().is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
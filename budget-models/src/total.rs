@@ -7,7 +7,7 @@
 //
 // CREATED:         07/07/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,7 +24,13 @@
 // limitations under the License.
 ////
 
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+
 use crate::calculation::Calculation;
+use crate::currency::Currency;
+use crate::models::{AccountClass, AccountType, TransactionType};
 use crate::money::Money;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -32,21 +38,547 @@ use crate::money::Money;
 //  The BurnUpTotal represents an incremental tracking measure of "Burn-up" of
 //  a thing. As transactions are "applied" to the thing, the total increases
 //  monotonically by the amount of each transaction.
+//
+//  Like the other totals here, the result is None once an input can't be
+//  combined with the total, e.g. an amount in another currency.
 ////
 
-#[derive(Clone, Copy, Debug, Default)]
-pub struct BurnUpTotal(Money);
+#[derive(Clone, Copy, Debug)]
+pub struct BurnUpTotal(Option<Money>);
+
+impl Default for BurnUpTotal {
+    fn default() -> Self {
+        Self(Some(Money::default()))
+    }
+}
+
+impl BurnUpTotal {
+    // A total that reports in the given currency. Inputs must already be
+    // converted to this currency.
+    pub fn new(currency: Currency) -> Self {
+        Self(Some(Money::zero(currency)))
+    }
+}
 
 impl Calculation for BurnUpTotal {
     type Input = Money;
-    type Result = Money;
+    type Result = Option<Money>;
     fn apply(&mut self, input: Self::Input) {
-        self.0.add(input);
+        self.0 = self.0.and_then(|total| total.checked_add(input));
     }
 
-    fn calculate(&self) -> &Money {
+    fn calculate(&self) -> &Option<Money> {
         &self.0
     }
 }
+///////////////////////////////////////////////////////////////////////////////
+// BurnDownTotal
+//  The BurnDownTotal tracks how much of a budgeted amount remains. Each
+//  transaction applied to it is spent from the budget, so the total falls
+//  from the budgeted amount towards (and possibly past) zero.
+////
+
+#[derive(Clone, Copy, Debug)]
+pub struct BurnDownTotal {
+    budgeted: Money,
+    remaining: Option<Money>,
+}
+
+impl Default for BurnDownTotal {
+    fn default() -> Self {
+        Self::new(Money::default())
+    }
+}
+
+impl BurnDownTotal {
+    pub fn new(budgeted: Money) -> Self {
+        Self {
+            budgeted,
+            remaining: Some(budgeted),
+        }
+    }
+
+    pub fn budgeted(&self) -> Money {
+        self.budgeted
+    }
+
+    pub fn spent(&self) -> Option<Money> {
+        self.remaining
+            .and_then(|remaining| self.budgeted.checked_sub(remaining))
+    }
+}
+
+impl Calculation for BurnDownTotal {
+    type Input = Money;
+    type Result = Option<Money>;
+    fn apply(&mut self, input: Self::Input) {
+        self.remaining = self
+            .remaining
+            .and_then(|remaining| remaining.checked_sub(input));
+    }
+
+    fn calculate(&self) -> &Option<Money> {
+        &self.remaining
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RunningBalance
+//  The RunningBalance adds each transaction to a balance, and keeps every
+//  intermediate value of the balance, e.g. for plotting balance over time.
+////
+
+#[derive(Clone, Debug)]
+pub struct RunningBalance {
+    balance: Money,
+    history: Option<Vec<Money>>,
+}
+
+impl Default for RunningBalance {
+    fn default() -> Self {
+        Self::new(Money::default())
+    }
+}
+
+impl RunningBalance {
+    // A running balance starting from an opening balance. The opening balance
+    // is not part of the history.
+    pub fn new(opening: Money) -> Self {
+        Self {
+            balance: opening,
+            history: Some(Vec::new()),
+        }
+    }
+
+    pub fn balance(&self) -> Option<Money> {
+        self.history.as_ref().map(|_| self.balance)
+    }
+}
+
+impl Calculation for RunningBalance {
+    type Input = Money;
+    type Result = Option<Vec<Money>>;
+    fn apply(&mut self, input: Self::Input) {
+        if let Some(history) = self.history.as_mut() {
+            match self.balance.checked_add(input) {
+                Some(balance) => {
+                    self.balance = balance;
+                    history.push(balance);
+                }
+                None => self.history = None,
+            }
+        }
+    }
+
+    fn calculate(&self) -> &Option<Vec<Money>> {
+        &self.history
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MovingAverage
+//  The average of the last N periods, e.g. "Last Six Months (Average
+//  Spent)". Each input is the total of one period. Until N periods have been
+//  applied, the average is over the periods seen so far. The average is None
+//  while the window holds amounts that can't be combined.
+////
+
+#[derive(Clone, Debug)]
+pub struct MovingAverage {
+    periods: usize,
+    window: VecDeque<Money>,
+    average: Option<Money>,
+}
+
+impl MovingAverage {
+    // The number of periods usually comes from the user, so it's up to the
+    // caller to reject zero.
+    pub fn new(periods: NonZeroUsize) -> Self {
+        let periods = periods.get();
+        Self {
+            periods,
+            window: VecDeque::with_capacity(periods),
+            average: Some(Money::default()),
+        }
+    }
+}
+
+impl Calculation for MovingAverage {
+    type Input = Money;
+    type Result = Option<Money>;
+    fn apply(&mut self, input: Self::Input) {
+        if self.window.len() == self.periods {
+            self.window.pop_front();
+        }
+        self.window.push_back(input);
+
+        let length = self.window.len() as i64;
+        self.average = Money::checked_sum(self.window.iter().copied())
+            .and_then(|total| total.scale(1, length));
+    }
+
+    fn calculate(&self) -> &Option<Money> {
+        &self.average
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MinMax
+//  Tracks the smallest and largest values applied, e.g. the best and worst
+//  months of spending.
+////
+
+#[derive(Clone, Copy, Debug)]
+pub struct MinMax<T> {
+    range: Option<(T, T)>,
+}
+
+impl<T> Default for MinMax<T> {
+    fn default() -> Self {
+        Self { range: None }
+    }
+}
+
+impl<T: Copy + Ord> MinMax<T> {
+    pub fn min(&self) -> Option<T> {
+        self.range.map(|(min, _)| min)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.range.map(|(_, max)| max)
+    }
+}
+
+impl<T: Copy + Ord> Calculation for MinMax<T> {
+    type Input = T;
+    type Result = Option<(T, T)>;
+    fn apply(&mut self, input: Self::Input) {
+        self.range = Some(match self.range {
+            Some((min, max)) => (min.min(input), max.max(input)),
+            None => (input, input),
+        });
+    }
+
+    fn calculate(&self) -> &Option<(T, T)> {
+        &self.range
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// BudgetVariance
+//  Compares planned transactions to real ones. The variance is real minus
+//  planned, so for spending (negative amounts) a negative variance means more
+//  was spent than planned.
+////
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Variance {
+    pub planned: Money,
+    pub real: Money,
+    pub variance: Money,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BudgetVariance(Option<Variance>);
+
+impl Default for BudgetVariance {
+    fn default() -> Self {
+        Self(Some(Variance::default()))
+    }
+}
+
+impl BudgetVariance {
+    pub fn new(currency: Currency) -> Self {
+        Self(Some(Variance {
+            planned: Money::zero(currency),
+            real: Money::zero(currency),
+            variance: Money::zero(currency),
+        }))
+    }
+}
+
+impl Calculation for BudgetVariance {
+    type Input = (TransactionType, Money);
+    type Result = Option<Variance>;
+    fn apply(&mut self, input: Self::Input) {
+        let (transaction_type, amount) = input;
+        self.0 = self.0.and_then(|Variance { planned, real, .. }| {
+            let (planned, real) = match transaction_type {
+                TransactionType::Planned => {
+                    (planned.checked_add(amount)?, real)
+                }
+                TransactionType::Real => (planned, real.checked_add(amount)?),
+            };
+            Some(Variance {
+                planned,
+                real,
+                variance: real.checked_sub(planned)?,
+            })
+        });
+    }
+
+    fn calculate(&self) -> &Option<Variance> {
+        &self.0
+    }
+}
+///////////////////////////////////////////////////////////////////////////////
+// DistinctCount
+//  Counts the distinct values applied, e.g. the number of transactions when
+//  split transactions contribute several rows.
+////
+
+#[derive(Clone, Debug)]
+pub struct DistinctCount<T> {
+    seen: HashSet<T>,
+    count: usize,
+}
+
+impl<T> Default for DistinctCount<T> {
+    fn default() -> Self {
+        Self {
+            seen: HashSet::new(),
+            count: 0,
+        }
+    }
+}
+
+impl<T: Eq + Hash> Calculation for DistinctCount<T> {
+    type Input = T;
+    type Result = usize;
+    fn apply(&mut self, input: Self::Input) {
+        if self.seen.insert(input) {
+            self.count += 1;
+        }
+    }
+
+    fn calculate(&self) -> &usize {
+        &self.count
+    }
+}
+///////////////////////////////////////////////////////////////////////////////
+// NetWorth
+//  Totals the balances of accounts by their class. Balances are signed as in
+//  the ledger, so a liability that's owed (e.g. a credit card carrying a
+//  balance) has a negative balance. Liabilities are reported as the amount
+//  owed, i.e. the negated balance, and net worth is the sum of the signed
+//  balances: assets minus liabilities.
+////
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Worth {
+    pub assets: Money,
+    pub liabilities: Money,
+    pub net_worth: Money,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NetWorth(Option<Worth>);
+
+impl Default for NetWorth {
+    fn default() -> Self {
+        Self(Some(Worth::default()))
+    }
+}
+
+impl NetWorth {
+    pub fn new(currency: Currency) -> Self {
+        Self(Some(Worth {
+            assets: Money::zero(currency),
+            liabilities: Money::zero(currency),
+            net_worth: Money::zero(currency),
+        }))
+    }
+
+    // The total owed on all liability accounts.
+    pub fn debt(&self) -> Option<Money> {
+        self.0.map(|worth| worth.liabilities)
+    }
+}
+
+impl Calculation for NetWorth {
+    type Input = (AccountType, Money);
+    type Result = Option<Worth>;
+    fn apply(&mut self, input: Self::Input) {
+        let (account_type, balance) = input;
+        self.0 = self.0.and_then(
+            |Worth {
+                 assets,
+                 liabilities,
+                 ..
+             }| {
+                let (assets, liabilities) = match account_type.class() {
+                    AccountClass::Asset => {
+                        (assets.checked_add(balance)?, liabilities)
+                    }
+                    AccountClass::Liability => {
+                        (assets, liabilities.checked_sub(balance)?)
+                    }
+                };
+                Some(Worth {
+                    assets,
+                    liabilities,
+                    net_worth: assets.checked_sub(liabilities)?,
+                })
+            },
+        );
+    }
+
+    fn calculate(&self) -> &Option<Worth> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(amount: i64) -> Money {
+        Money::new(amount, Currency::Usd)
+    }
+
+    fn eur(amount: i64) -> Money {
+        Money::new(amount, Currency::Eur)
+    }
+
+    fn apply<C: Calculation>(mut calculation: C, inputs: Vec<C::Input>) -> C {
+        for input in inputs {
+            calculation.apply(input);
+        }
+        calculation
+    }
+
+    #[test]
+    fn burn_up_sums_the_inputs() {
+        let total =
+            apply(BurnUpTotal::new(Currency::Usd), vec![usd(150), usd(-50)]);
+        assert_eq!(&Some(usd(100)), total.calculate());
+
+        let total = apply(BurnUpTotal::default(), vec![eur(150)]);
+        assert_eq!(&Some(eur(150)), total.calculate());
+    }
+
+    #[test]
+    fn totals_fail_on_mixed_currencies() {
+        let total =
+            apply(BurnUpTotal::default(), vec![usd(1), eur(1), eur(-1)]);
+        assert_eq!(&None, total.calculate());
+
+        let total = apply(RunningBalance::new(usd(100)), vec![usd(1), eur(1)]);
+        assert_eq!(None, total.balance());
+        assert_eq!(&None, total.calculate());
+
+        let periods = NonZeroUsize::new(2).unwrap();
+        let average = apply(MovingAverage::new(periods), vec![usd(1), eur(1)]);
+        assert_eq!(&None, average.calculate());
+    }
+
+    #[test]
+    fn burn_down_spends_from_the_budget() {
+        let total = apply(
+            BurnDownTotal::new(usd(50000)),
+            vec![usd(12000), usd(45000)],
+        );
+        assert_eq!(usd(50000), total.budgeted());
+        assert_eq!(Some(usd(57000)), total.spent());
+        assert_eq!(&Some(usd(-7000)), total.calculate());
+    }
+
+    #[test]
+    fn running_balance_keeps_its_history() {
+        let balance = apply(
+            RunningBalance::new(usd(1000)),
+            vec![usd(-250), usd(500), usd(-1250)],
+        );
+        assert_eq!(Some(usd(0)), balance.balance());
+        assert_eq!(
+            &Some(vec![usd(750), usd(1250), usd(0)]),
+            balance.calculate()
+        );
+    }
+
+    #[test]
+    fn moving_average_covers_the_last_periods() {
+        let periods = NonZeroUsize::new(3).unwrap();
+        let mut average = MovingAverage::new(periods);
+        average.apply(usd(100));
+        average.apply(usd(200));
+        assert_eq!(&Some(usd(150)), average.calculate());
+        average.apply(usd(400));
+        average.apply(usd(700));
+        // (200 + 400 + 700) / 3 = 433.33...
+        assert_eq!(&Some(usd(433)), average.calculate());
+
+        // An amount in another currency only spoils the average while it's
+        // in the window.
+        let periods = NonZeroUsize::new(1).unwrap();
+        let average = apply(MovingAverage::new(periods), vec![usd(1), eur(5)]);
+        assert_eq!(&Some(eur(5)), average.calculate());
+    }
+
+    #[test]
+    fn min_max_tracks_the_range() {
+        let range = MinMax::<i64>::default();
+        assert_eq!(None, range.min());
+        let range = apply(range, vec![5, -3, 12, 0]);
+        assert_eq!(Some(-3), range.min());
+        assert_eq!(Some(12), range.max());
+        assert_eq!(&Some((-3, 12)), range.calculate());
+    }
+
+    #[test]
+    fn variance_is_real_minus_planned() {
+        let variance = apply(
+            BudgetVariance::new(Currency::Usd),
+            vec![
+                (TransactionType::Planned, usd(-10000)),
+                (TransactionType::Real, usd(-8000)),
+                (TransactionType::Real, usd(-4500)),
+            ],
+        );
+        assert_eq!(
+            &Some(Variance {
+                planned: usd(-10000),
+                real: usd(-12500),
+                variance: usd(-2500),
+            }),
+            variance.calculate()
+        );
+
+        let variance = apply(
+            BudgetVariance::new(Currency::Usd),
+            vec![
+                (TransactionType::Planned, usd(-100)),
+                (TransactionType::Real, eur(-100)),
+            ],
+        );
+        assert_eq!(&None, variance.calculate());
+    }
+
+    #[test]
+    fn net_worth_sums_signed_balances() {
+        let worth = apply(
+            NetWorth::new(Currency::Usd),
+            vec![
+                (AccountType::Checking, usd(250000)),
+                (AccountType::Credit, usd(-40000)),
+            ],
+        );
+        assert_eq!(Some(usd(40000)), worth.debt());
+        assert_eq!(
+            &Some(Worth {
+                assets: usd(250000),
+                liabilities: usd(40000),
+                net_worth: usd(210000),
+            }),
+            worth.calculate()
+        );
+    }
+
+    #[test]
+    fn distinct_count_ignores_repeats() {
+        let count = apply(DistinctCount::default(), vec![3, 1, 3, 2, 1]);
+        assert_eq!(&3, count.calculate());
+    }
+}
 
 ///////////////////////////////////////////////////////////////////////////////
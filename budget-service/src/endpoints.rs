@@ -7,7 +7,7 @@
 //
 // CREATED:         07/04/2022
 //
-// LAST EDITED:     11/20/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,5 +25,6 @@
 ////
 
 pub mod accounts;
+pub mod transactions;
 
 ///////////////////////////////////////////////////////////////////////////////
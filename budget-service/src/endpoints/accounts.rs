@@ -7,7 +7,7 @@
 //
 // CREATED:         07/04/2022
 //
-// LAST EDITED:     11/15/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -24,20 +24,19 @@
 // limitations under the License.
 ////
 
-use axum::{http::StatusCode, Json};
+use axum::Json;
+use budget_backend_lib::error::Error;
 use budget_backend_lib::prelude::*;
 use budget_models::models;
 use sea_orm::{DatabaseConnection, EntityTrait};
 
-use crate::internal_server_error;
+use crate::error::ApiError;
 
 pub async fn list(
     db: DatabaseConnection,
-) -> Result<Json<Vec<models::Account>>, StatusCode> {
-    let accounts: Vec<accounts::Model> = Accounts::find()
-        .all(&db)
-        .await
-        .map_err(internal_server_error)?;
+) -> Result<Json<Vec<models::Account>>, ApiError> {
+    let accounts: Vec<accounts::Model> =
+        Accounts::find().all(&db).await.map_err(Error::from)?;
     Ok(Json(
         accounts.into_iter().map(|account| account.into()).collect(),
     ))
@@ -0,0 +1,170 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            transactions.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Logic encapsulating Transactions endpoints.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use axum::{http::StatusCode, Json};
+use budget_backend_lib::error::Error;
+use budget_backend_lib::repository::DatabaseRepository;
+use budget_backend_lib::{suggestion, transaction, validation};
+use budget_models::models::{self, TagFilter, TransactionFilter, Violation};
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+
+use crate::error::ApiError;
+
+// Query parameters for listing transactions. Dates are RFC 3339, and tags
+// are comma-separated. Transactions having any of the tags are selected.
+#[derive(Deserialize)]
+pub struct ListQuery {
+    transaction_type: Option<models::TransactionType>,
+    account: Option<String>,
+    periodic_budget: Option<i32>,
+    one_time_budget: Option<i32>,
+    line_item: Option<String>,
+    start_date: Option<DateTime<Utc>>,
+    end_date: Option<DateTime<Utc>>,
+    tags: Option<String>,
+    payee: Option<i32>,
+}
+
+impl Into<TransactionFilter> for ListQuery {
+    fn into(self) -> TransactionFilter {
+        TransactionFilter {
+            transaction_type: self.transaction_type,
+            account: self.account,
+            periodic_budget: self.periodic_budget,
+            one_time_budget: self.one_time_budget,
+            line_item: self.line_item,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            payee: self.payee,
+            tags: self.tags.map(|tags| {
+                TagFilter::Any(
+                    tags.split(',')
+                        .map(|tag| tag.trim().to_string())
+                        .collect(),
+                )
+            }),
+        }
+    }
+}
+
+pub async fn list(
+    db: DatabaseConnection,
+    query: ListQuery,
+) -> Result<Json<Vec<models::Transaction>>, ApiError> {
+    transaction::list(&db, &query.into())
+        .await
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+pub async fn get(
+    db: DatabaseConnection,
+    id: i32,
+) -> Result<Json<models::Transaction>, ApiError> {
+    transaction::get(&db, id)
+        .await
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+// Check a transaction without creating it. The response lists every
+// violation, and is empty if the transaction is valid.
+pub async fn validate(
+    db: DatabaseConnection,
+    transaction: models::NewTransaction,
+) -> Result<Json<Vec<Violation>>, ApiError> {
+    let context = validation::load_context(&db).await.map_err(Error::from)?;
+    Ok(Json(context.violations(&transaction)))
+}
+
+// Create a transaction. Invalid transactions are rejected with the list of
+// violations.
+pub async fn create(
+    db: DatabaseConnection,
+    new_transaction: models::NewTransaction,
+) -> Result<(StatusCode, Json<models::Transaction>), ApiError> {
+    transaction::create(&db, new_transaction)
+        .await
+        .map(|created| (StatusCode::CREATED, Json(created)))
+        .map_err(ApiError::from)
+}
+
+// Replace a transaction, given the id of any of its legs.
+pub async fn update(
+    db: DatabaseConnection,
+    id: i32,
+    new_transaction: models::NewTransaction,
+) -> Result<Json<models::Transaction>, ApiError> {
+    transaction::update(&db, id, new_transaction)
+        .await
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+// Suggested line items for a transaction, most confident first.
+pub async fn suggestions(
+    db: DatabaseConnection,
+    id: i32,
+) -> Result<Json<Vec<models::LineItemSuggestion>>, ApiError> {
+    suggestion::suggest(&DatabaseRepository::new(db), id)
+        .await
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Deserialize)]
+pub struct Categorization {
+    line_item: String,
+}
+
+// Allocate a real transaction entirely to a line item, e.g. to accept one of
+// its suggestions.
+pub async fn categorize(
+    db: DatabaseConnection,
+    id: i32,
+    categorization: Categorization,
+) -> Result<Json<models::Transaction>, ApiError> {
+    let repository = DatabaseRepository::new(db);
+    suggestion::accept(&repository, id, &categorization.line_item)
+        .await
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+// Delete a transaction, and every leg of its series.
+pub async fn delete(
+    db: DatabaseConnection,
+    id: i32,
+) -> Result<StatusCode, ApiError> {
+    transaction::delete(&db, id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,110 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            error.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Conversion of backend errors to HTTP responses.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+////
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use budget_backend_lib::error::Error;
+use budget_models::models::Violation;
+use serde::Serialize;
+use tracing::{event, Level};
+
+///////////////////////////////////////////////////////////////////////////////
+// ErrorBody
+// The JSON body of every error response. Failures of the database or of the
+// configuration are logged, but their details are not returned to clients.
+////
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    violations: Vec<Violation>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ApiError
+// Wraps the backend error so that endpoints can return it directly.
+////
+
+#[derive(Debug)]
+pub(crate) struct ApiError(Error);
+
+impl From<Error> for ApiError {
+    fn from(value: Error) -> Self {
+        Self(value)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let record = match &self.0 {
+            Error::FailedRecord { index, .. } => Some(*index),
+            _ => None,
+        };
+
+        let (status, error) = match self.0.root() {
+            Error::NotFound { .. } => (StatusCode::NOT_FOUND, "not_found"),
+            Error::Validation(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "validation")
+            }
+            Error::Conflict(_) => (StatusCode::CONFLICT, "conflict"),
+            Error::Configuration(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "configuration")
+            }
+            Error::Database(_) | Error::FailedRecord { .. } => {
+                (StatusCode::INTERNAL_SERVER_ERROR, "database")
+            }
+        };
+
+        let message = if status == StatusCode::INTERNAL_SERVER_ERROR {
+            event!(Level::ERROR, "{:?}", &self.0);
+            "internal server error".to_string()
+        } else {
+            self.0.to_string()
+        };
+
+        let violations = match self.0.root() {
+            Error::Validation(e) => e.violations().to_vec(),
+            _ => Vec::new(),
+        };
+
+        let body = ErrorBody {
+            error,
+            message,
+            record,
+            violations,
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
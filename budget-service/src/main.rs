@@ -7,7 +7,7 @@
 //
 // CREATED:         04/10/2022
 //
-// LAST EDITED:     11/20/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -25,30 +25,20 @@
 ////
 
 use axum::{
-    http::StatusCode,
-    routing::get,
-    Router,
+    extract::{Path, Query},
+    routing::{get, post},
+    Json, Router,
 };
-use budget_backend_lib::secret::SecretManager;
+use budget_backend_lib::secret::{SecretFormat, SecretManager};
 use clap::Parser;
 use sea_orm::Database;
 use serde::Deserialize;
 use std::env;
-use std::fmt;
 use std::fs::File;
 use tower_http::trace::TraceLayer;
-use tracing::{event, Level};
 
 mod endpoints;
-
-///////////////////////////////////////////////////////////////////////////////
-// internal_server_error Helper
-////
-
-pub(crate) fn internal_server_error<E: fmt::Debug>(e: E) -> StatusCode {
-    event!(Level::ERROR, "{:?}", &e);
-    StatusCode::INTERNAL_SERVER_ERROR
-}
+mod error;
 
 ///////////////////////////////////////////////////////////////////////////////
 // Configuration
@@ -75,9 +65,14 @@ impl Default for Configuration {
 #[derive(Parser, Debug)]
 #[clap(author, version)]
 struct Args {
-    /// Path to a JSON file containing database credentials
+    /// Path to the file or directory containing database credentials
     #[clap(short, long, value_parser)]
-    secret_file: String,
+    secret_file: Option<String>,
+
+    /// Where to read database credentials from: json, yaml, toml, env,
+    /// directory (one file per key) or pgpass
+    #[clap(long, value_parser, default_value = "json")]
+    secret_format: SecretFormat,
 
     /// Path to a YAML file containing application configuration
     #[clap(short, long, value_parser)]
@@ -98,7 +93,8 @@ async fn main() -> anyhow::Result<()> {
 
     let url_template =
         env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let secret_manager = SecretManager::new(args.secret_file);
+    let source = args.secret_format.source(args.secret_file)?;
+    let secret_manager = SecretManager::new(source);
     let url = secret_manager.with_url(url_template.parse()?)?;
 
     let configuration: Configuration = match args.config_file {
@@ -117,6 +113,61 @@ async fn main() -> anyhow::Result<()> {
                 move || endpoints::accounts::list(db)
             }),
         )
+        .route(
+            "/api/transactions",
+            get({
+                let db = connection.clone();
+                move |Query(query)| endpoints::transactions::list(db, query)
+            })
+            .post({
+                let db = connection.clone();
+                move |Json(transaction)| {
+                    endpoints::transactions::create(db, transaction)
+                }
+            }),
+        )
+        .route(
+            "/api/transactions/:id",
+            get({
+                let db = connection.clone();
+                move |Path(id)| endpoints::transactions::get(db, id)
+            })
+            .put({
+                let db = connection.clone();
+                move |Path(id), Json(transaction)| {
+                    endpoints::transactions::update(db, id, transaction)
+                }
+            })
+            .delete({
+                let db = connection.clone();
+                move |Path(id)| endpoints::transactions::delete(db, id)
+            }),
+        )
+        .route(
+            "/api/transactions/:id/suggestions",
+            get({
+                let db = connection.clone();
+                move |Path(id)| endpoints::transactions::suggestions(db, id)
+            }),
+        )
+        .route(
+            "/api/transactions/:id/categorize",
+            post({
+                let db = connection.clone();
+                move |Path(id), Json(categorization)| {
+                    endpoints::transactions::categorize(db, id, categorization)
+                }
+            }),
+        )
+        .route(
+            "/api/transactions/validate",
+            post({
+                let db = connection.clone();
+                move |Json(transaction)| {
+                    endpoints::transactions::validate(db, transaction)
+                }
+            }),
+        )
         .layer(TraceLayer::new_for_http());
 
     let root = configuration.root.as_ref().unwrap();
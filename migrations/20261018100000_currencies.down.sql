@@ -0,0 +1,3 @@
+DROP TABLE exchange_rates;
+ALTER TABLE accounts DROP COLUMN currency;
+DROP TYPE Currency;
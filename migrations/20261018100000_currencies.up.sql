@@ -0,0 +1,11 @@
+CREATE TYPE Currency AS ENUM('USD', 'AUD', 'CAD', 'EUR', 'GBP', 'JPY', 'MXN');
+ALTER TABLE accounts ADD COLUMN currency Currency NOT NULL DEFAULT 'USD';
+
+CREATE TABLE exchange_rates (
+       id SERIAL PRIMARY KEY,
+       date timestamp with TIME ZONE NOT NULL,
+       from_currency Currency NOT NULL,
+       to_currency Currency NOT NULL,
+       rate NUMERIC(18, 8) NOT NULL,
+       UNIQUE(date, from_currency, to_currency)
+);
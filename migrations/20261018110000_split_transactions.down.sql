@@ -0,0 +1,2 @@
+ALTER TABLE planned_transactions DROP COLUMN amount;
+ALTER TABLE real_transactions DROP COLUMN amount;
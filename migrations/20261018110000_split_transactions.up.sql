@@ -0,0 +1,10 @@
+ALTER TABLE real_transactions ADD COLUMN amount BIGINT;
+UPDATE real_transactions SET amount = transactions.amount
+       FROM transactions WHERE transactions.id = real_transactions.transaction;
+ALTER TABLE real_transactions ALTER COLUMN amount SET NOT NULL;
+
+ALTER TABLE planned_transactions ADD COLUMN amount BIGINT;
+UPDATE planned_transactions SET amount = transactions.amount
+       FROM transactions
+       WHERE transactions.id = planned_transactions.transaction;
+ALTER TABLE planned_transactions ALTER COLUMN amount SET NOT NULL;
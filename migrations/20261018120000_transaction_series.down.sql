@@ -0,0 +1,13 @@
+ALTER TABLE transactions ADD COLUMN completed_by INTEGER;
+ALTER TABLE transactions ADD FOREIGN KEY(completed_by)
+      REFERENCES transactions(id) ON DELETE CASCADE;
+
+-- Every leg of a series is completed by the first leg of the series.
+UPDATE transactions SET completed_by = first.id
+       FROM (SELECT series, MIN(id) AS id FROM transactions
+                    WHERE series IS NOT NULL GROUP BY series) AS first
+       WHERE transactions.series = first.series
+       AND transactions.id != first.id;
+
+ALTER TABLE transactions DROP COLUMN series;
+DROP TABLE transaction_series;
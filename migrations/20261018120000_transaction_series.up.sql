@@ -0,0 +1,31 @@
+CREATE TABLE transaction_series (
+       id SERIAL PRIMARY KEY
+);
+
+ALTER TABLE transactions ADD COLUMN series INTEGER;
+ALTER TABLE transactions ADD FOREIGN KEY(series)
+      REFERENCES transaction_series(id) ON DELETE CASCADE;
+
+-- Find the first leg of every completed_by chain. Each chain becomes a
+-- series, which borrows the id of its first leg.
+CREATE TEMPORARY TABLE chains AS
+       WITH RECURSIVE chain(id, root) AS (
+            SELECT id, id FROM transactions WHERE completed_by IS NULL
+            UNION ALL
+            SELECT transactions.id, chain.root FROM transactions
+                   JOIN chain ON transactions.completed_by = chain.id
+       )
+       SELECT id, root FROM chain;
+
+INSERT INTO transaction_series (id)
+       SELECT DISTINCT root FROM chains WHERE id != root;
+SELECT setval(pg_get_serial_sequence('transaction_series', 'id'),
+       COALESCE(MAX(id), 0) + 1, false) FROM transaction_series;
+
+UPDATE transactions SET series = chains.root
+       FROM chains
+       WHERE chains.id = transactions.id
+       AND chains.root IN (SELECT id FROM transaction_series);
+
+DROP TABLE chains;
+ALTER TABLE transactions DROP COLUMN completed_by;
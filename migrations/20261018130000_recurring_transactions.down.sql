@@ -0,0 +1,2 @@
+ALTER TABLE transactions DROP COLUMN recurring_transaction;
+DROP TABLE recurring_transactions;
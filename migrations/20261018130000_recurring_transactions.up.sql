@@ -0,0 +1,17 @@
+CREATE TABLE recurring_transactions (
+       id SERIAL PRIMARY KEY,
+       summary TEXT NOT NULL,
+       from_account TEXT,
+       to_account TEXT,
+       amount BIGINT NOT NULL,
+       line_item TEXT NOT NULL,
+       rule JSONB NOT NULL,
+       FOREIGN KEY(from_account) REFERENCES accounts(name),
+       FOREIGN KEY(to_account) REFERENCES accounts(name),
+       FOREIGN KEY(line_item) REFERENCES line_items(summary),
+       CHECK(from_account IS NOT NULL OR to_account IS NOT NULL)
+);
+
+ALTER TABLE transactions ADD COLUMN recurring_transaction INTEGER;
+ALTER TABLE transactions ADD FOREIGN KEY(recurring_transaction)
+      REFERENCES recurring_transactions(id) ON DELETE SET NULL;
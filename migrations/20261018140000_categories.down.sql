@@ -0,0 +1,2 @@
+ALTER TABLE line_items DROP COLUMN category;
+DROP TABLE categories;
@@ -0,0 +1,10 @@
+CREATE TABLE categories (
+       name TEXT NOT NULL UNIQUE PRIMARY KEY,
+       parent TEXT,
+       FOREIGN KEY(parent) REFERENCES categories(name),
+       CHECK(parent IS NULL OR parent <> name)
+);
+
+ALTER TABLE line_items ADD COLUMN category TEXT;
+ALTER TABLE line_items ADD FOREIGN KEY(category)
+      REFERENCES categories(name) ON DELETE SET NULL;
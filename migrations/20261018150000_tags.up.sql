@@ -0,0 +1,14 @@
+CREATE TABLE tags (
+       id SERIAL PRIMARY KEY,
+       tag TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE transaction_tags (
+       transaction INTEGER NOT NULL,
+       tag INTEGER NOT NULL,
+       PRIMARY KEY(transaction, tag),
+       FOREIGN KEY(transaction) REFERENCES transactions(id)
+            ON DELETE CASCADE,
+       FOREIGN KEY(tag) REFERENCES tags(id)
+            ON DELETE CASCADE
+);
@@ -0,0 +1,12 @@
+-- Planned transactions which only belong to a one-time budget can't be
+-- represented without them.
+DELETE FROM transactions WHERE id IN (
+       SELECT transaction FROM planned_transactions
+       WHERE periodic_budget IS NULL
+);
+ALTER TABLE planned_transactions DROP CONSTRAINT planned_transactions_budget;
+ALTER TABLE planned_transactions ALTER COLUMN periodic_budget SET NOT NULL;
+ALTER TABLE planned_transactions DROP COLUMN one_time_budget;
+ALTER TABLE real_transactions DROP COLUMN one_time_budget;
+DROP TABLE one_time_budgets;
+DROP TYPE OneTimeBudgetStatus;
@@ -0,0 +1,25 @@
+CREATE TYPE OneTimeBudgetStatus AS ENUM('active', 'completed', 'cancelled');
+CREATE TABLE one_time_budgets (
+       id SERIAL PRIMARY KEY,
+       description TEXT NOT NULL,
+       target BIGINT NOT NULL,
+       account TEXT,
+       start_date timestamp with TIME ZONE,
+       end_date timestamp with TIME ZONE,
+       status OneTimeBudgetStatus NOT NULL DEFAULT 'active',
+       FOREIGN KEY(account) REFERENCES accounts(name),
+       CHECK(start_date IS NULL OR end_date IS NULL OR start_date <= end_date)
+);
+
+ALTER TABLE real_transactions ADD COLUMN one_time_budget INTEGER;
+ALTER TABLE real_transactions ADD FOREIGN KEY(one_time_budget)
+      REFERENCES one_time_budgets(id);
+
+-- Planned transactions may now belong to a one-time budget instead of a
+-- periodic one, but they must still belong to some budget.
+ALTER TABLE planned_transactions ADD COLUMN one_time_budget INTEGER;
+ALTER TABLE planned_transactions ADD FOREIGN KEY(one_time_budget)
+      REFERENCES one_time_budgets(id);
+ALTER TABLE planned_transactions ALTER COLUMN periodic_budget DROP NOT NULL;
+ALTER TABLE planned_transactions ADD CONSTRAINT planned_transactions_budget
+      CHECK(periodic_budget IS NOT NULL OR one_time_budget IS NOT NULL);
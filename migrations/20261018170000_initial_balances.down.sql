@@ -0,0 +1 @@
+DROP TABLE initial_balances;
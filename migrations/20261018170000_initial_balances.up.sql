@@ -0,0 +1,11 @@
+CREATE TABLE initial_balances (
+       id SERIAL PRIMARY KEY,
+       account TEXT NOT NULL,
+       budget INTEGER NOT NULL,
+       balance BIGINT NOT NULL,
+       last_updated timestamp with TIME ZONE NOT NULL DEFAULT now(),
+       FOREIGN KEY(account) REFERENCES accounts(name),
+       FOREIGN KEY(budget) REFERENCES periodic_budgets(id)
+            ON DELETE CASCADE,
+       UNIQUE(account, budget)
+);
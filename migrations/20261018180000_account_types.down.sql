@@ -0,0 +1,7 @@
+-- Values can't be removed from an enum, so the type is recreated. This fails
+-- if any account still uses one of the new account types.
+ALTER TYPE AccountType RENAME TO AccountType_old;
+CREATE TYPE AccountType AS ENUM('checking', 'saving', 'credit', 'loan');
+ALTER TABLE accounts ALTER COLUMN account_type TYPE AccountType
+      USING account_type::text::AccountType;
+DROP TYPE AccountType_old;
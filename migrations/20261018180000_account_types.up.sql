@@ -0,0 +1,6 @@
+ALTER TYPE AccountType ADD VALUE 'investment';
+ALTER TYPE AccountType ADD VALUE 'cash';
+ALTER TYPE AccountType ADD VALUE 'retirement';
+ALTER TYPE AccountType ADD VALUE 'hsa';
+ALTER TYPE AccountType ADD VALUE 'mortgage';
+ALTER TYPE AccountType ADD VALUE 'other';
@@ -0,0 +1,15 @@
+CREATE TABLE reconciliations (
+       id SERIAL PRIMARY KEY,
+       account TEXT NOT NULL,
+       snapshot INTEGER NOT NULL,
+       start_date timestamp with TIME ZONE NOT NULL,
+       end_date timestamp with TIME ZONE NOT NULL,
+       adjustment INTEGER,
+       reconciled_at timestamp with TIME ZONE NOT NULL DEFAULT now(),
+       FOREIGN KEY(account) REFERENCES accounts(name),
+       FOREIGN KEY(snapshot) REFERENCES balance_snapshots(id)
+            ON DELETE CASCADE,
+       FOREIGN KEY(adjustment) REFERENCES transactions(id)
+            ON DELETE SET NULL,
+       UNIQUE(snapshot)
+);
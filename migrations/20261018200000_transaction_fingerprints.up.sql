@@ -0,0 +1 @@
+ALTER TABLE transactions ADD COLUMN fingerprint TEXT UNIQUE;
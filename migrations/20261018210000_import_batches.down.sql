@@ -0,0 +1,3 @@
+ALTER TABLE transactions DROP COLUMN import_batch;
+DROP TABLE import_batches;
+DROP TYPE TransactionType;
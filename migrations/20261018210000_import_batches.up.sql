@@ -0,0 +1,13 @@
+CREATE TYPE TransactionType AS ENUM('real', 'planned');
+
+CREATE TABLE import_batches (
+       id SERIAL PRIMARY KEY,
+       filename TEXT NOT NULL,
+       hash TEXT NOT NULL,
+       imported_at timestamp with TIME ZONE NOT NULL DEFAULT now(),
+       transaction_type TransactionType NOT NULL,
+       row_count INTEGER NOT NULL
+);
+
+ALTER TABLE transactions ADD COLUMN import_batch INTEGER
+      REFERENCES import_batches(id) ON DELETE SET NULL;
@@ -0,0 +1 @@
+DROP TABLE line_item_rules;
@@ -0,0 +1,13 @@
+CREATE TABLE line_item_rules (
+       id SERIAL PRIMARY KEY,
+       priority INTEGER NOT NULL DEFAULT 0,
+       line_item TEXT NOT NULL,
+       account TEXT,
+       pattern TEXT,
+       contains TEXT,
+       amount BIGINT,
+       FOREIGN KEY(line_item) REFERENCES line_items(summary)
+            ON DELETE CASCADE,
+       FOREIGN KEY(account) REFERENCES accounts(name)
+            ON DELETE CASCADE
+);
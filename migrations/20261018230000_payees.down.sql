@@ -0,0 +1,3 @@
+ALTER TABLE transactions DROP COLUMN payee;
+DROP TABLE payee_aliases;
+DROP TABLE payees;
@@ -0,0 +1,15 @@
+CREATE TABLE payees (
+       id SERIAL PRIMARY KEY,
+       name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE payee_aliases (
+       id SERIAL PRIMARY KEY,
+       payee INTEGER NOT NULL,
+       pattern TEXT NOT NULL,
+       FOREIGN KEY(payee) REFERENCES payees(id)
+            ON DELETE CASCADE
+);
+
+ALTER TABLE transactions ADD COLUMN payee INTEGER
+      REFERENCES payees(id) ON DELETE SET NULL;
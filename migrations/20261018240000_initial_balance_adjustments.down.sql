@@ -0,0 +1 @@
+ALTER TABLE initial_balances DROP COLUMN adjustment;
@@ -0,0 +1 @@
+ALTER TABLE initial_balances ADD COLUMN adjustment BIGINT;
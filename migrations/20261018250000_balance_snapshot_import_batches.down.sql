@@ -0,0 +1 @@
+ALTER TABLE balance_snapshots DROP COLUMN import_batch;
@@ -0,0 +1,2 @@
+ALTER TABLE balance_snapshots ADD COLUMN import_batch INTEGER
+      REFERENCES import_batches(id) ON DELETE SET NULL;